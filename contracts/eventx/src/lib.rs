@@ -1,6 +1,7 @@
 #![no_std]
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, vec, Address, Env, Map, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, Address, Env,
+    Symbol, Vec,
 };
 
 /// EventX NFT Ticketing Platform Smart Contract
@@ -16,11 +17,100 @@ use soroban_sdk::{
 #[contract]
 pub struct EventXContract;
 
+/// Contract hata kodları
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// Contract zaten initialize edilmiş
+    AlreadyInitialized = 1,
+    /// Contract henüz initialize edilmemiş
+    NotInitialized = 2,
+    /// Çağıran bu işlem için yetkili değil
+    Unauthorized = 3,
+    /// Event bulunamadı
+    EventNotFound = 4,
+    /// Ticket bulunamadı
+    TicketNotFound = 5,
+    /// Bilet fiyatı negatif olamaz
+    InvalidPrice = 6,
+    /// Event aktif değil
+    EventNotActive = 7,
+    /// Event iptal edilmiş
+    EventCancelled = 8,
+    /// Event için tüm biletler satılmış
+    SoldOut = 9,
+    /// Çağıran ticket'ın sahibi değil
+    NotOwner = 10,
+    /// Ticket zaten kullanılmış
+    AlreadyUsed = 11,
+    /// Ticket zaten refund edilmiş
+    AlreadyRefunded = 12,
+    /// Event iptal edilmemiş
+    EventNotCancelled = 13,
+    /// TTL eşiği uzatma değerinden büyük
+    InvalidTtlConfig = 14,
+    /// Event'in arşiv süresi dolmuş
+    EventArchived = 15,
+    /// Toplam bilet sayısı satılan bilet sayısının altına düşürülemez
+    InvalidTicketSupply = 16,
+    /// Bekleyen admin devri önerisi yok
+    NoPendingAdmin = 17,
+    /// Önerinin süresi dolmuş
+    ProposalExpired = 18,
+    /// Çağıran admin konseyinin üyesi değil
+    NotCouncilMember = 19,
+    /// İşlem admin konseyi kurulduğu için konsey önerisiyle yapılmalı
+    CouncilRequired = 20,
+    /// Üye öneriyi zaten onaylamış
+    AlreadyApproved = 21,
+    /// Konsey önerisi bulunamadı
+    ProposalNotFound = 22,
+    /// Konsey önerisi zaten uygulanmış
+    ProposalExecuted = 23,
+    /// Konsey üyeleri veya onay eşiği geçersiz
+    InvalidCouncil = 24,
+    /// Contract acil durum nedeniyle durdurulmuş
+    ContractPaused = 25,
+    /// Gelir henüz bekleme süresinde
+    RevenueLocked = 26,
+    /// Çekilecek bakiye yok
+    NothingToWithdraw = 27,
+    /// Geliri çekilmiş event iptal edilemez
+    RevenueWithdrawn = 28,
+    /// Platform ücreti oranı %100'ü aşıyor veya sabit ücret negatif
+    InvalidFee = 29,
+    /// Bilet kategorisi bulunamadı
+    TierNotFound = 30,
+    /// Bilet kategorisinin kapasitesi dolmuş
+    TierSoldOut = 31,
+    /// Kategori kapasitesi sıfır, event'in kategori sınırı aşılmış veya koltuklu bilette kategori seçilmiş
+    InvalidTier = 32,
+    /// Koltuk bölümü bulunamadı
+    SectionNotFound = 33,
+    /// Bölümde sıra veya koltuk yok ya da event'in bölüm sınırı aşılmış
+    InvalidSection = 34,
+    /// Bölümde böyle bir sıra veya koltuk yok
+    SeatNotFound = 35,
+    /// Koltuk daha önce satılmış
+    SeatTaken = 36,
+    /// Bilet adedi sıfır, event'in alım sınırını aşıyor veya koltuklu alımda birden fazla
+    InvalidQuantity = 37,
+    /// Event durdurulmuş
+    EventPaused = 38,
+    /// Bilet satılmış event'in tarihi öne çekilemez
+    InvalidEventDate = 39,
+    /// Refund transferi alıcıya ulaştırılamadı
+    RefundFailed = 40,
+    /// Event tarihi geldiği için satış kapandı
+    SalesClosed = 41,
+}
+
 /// Event bilgilerini tutan struct
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Event {
-    pub id: Symbol,
+    pub id: u64,
     pub title: Symbol,
     pub description: Symbol,
     pub organizer: Address,
@@ -30,26 +120,336 @@ pub struct Event {
     pub event_date: u64,
     pub is_active: bool,
     pub is_cancelled: bool,
+    /// Admin veya guardian tarafından durdurulmuş
+    pub is_paused: bool,
+    /// Bilet satışlarından contract'ta tutulan bakiye
+    pub escrow_balance: i128,
+    /// İptal sonrası refund işlemlerinin kaldığı ticket indeksi
+    pub refund_cursor: u32,
+    /// Organizatöre şimdiye kadar ödenen gelir
+    pub revenue_withdrawn: i128,
+    /// Satışlardan kesilen toplam platform ücreti (refund'lar düşülmüş)
+    pub platform_fees: i128,
+    /// Gelir çekilirken hazineye aktarılacak platform ücreti
+    pub fees_pending: i128,
+    /// İptal edilen event'te kullanılmış ticket'lardan organizatöre ayrılan gelir
+    pub used_revenue: i128,
+    /// Event'e tanımlı bilet kategorisi sayısı
+    pub tier_count: u32,
+    /// Event'e tanımlı koltuk bölümü sayısı
+    pub section_count: u32,
+    /// Tek bir satın almada alınabilecek en fazla bilet sayısı
+    pub max_per_purchase: u32,
 }
 
 /// Ticket bilgilerini tutan struct
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Ticket {
-    pub id: Symbol,
-    pub event_id: Symbol,
+    pub id: u64,
+    pub event_id: u64,
     pub owner: Address,
     pub is_used: bool,
     pub is_refunded: bool,
     pub purchase_date: u64,
+    /// Satın alma sırasında ödenen tutar
+    pub price_paid: i128,
+    /// `price_paid` içinden kesilen, iade edilmeyen platform ücreti
+    pub fee_paid: i128,
+    /// Event içindeki sıra numarası (1'den başlar)
+    pub serial: u32,
+    /// Sahibinin ticket indeksindeki sıra numarası (1'den başlar)
+    pub owner_slot: u32,
+    /// Satın alınan bilet kategorisi; `None` ise event'in standart bileti
+    pub tier: Option<u32>,
+    /// Ticket'a ayrılmış koltuk
+    pub seat: TicketSeat,
+}
+
+/// Bölüm içindeki tek bir koltuk
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Seat {
+    pub section: u32,
+    /// Sıra numarası (1'den başlar)
+    pub row: u32,
+    /// Sıra içindeki koltuk numarası (1'den başlar)
+    pub number: u32,
+}
+
+/// Ticket'ın koltuk ataması
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TicketSeat {
+    /// Koltuksuz (ayakta / serbest oturma) giriş
+    Unassigned,
+    /// Ayrılmış koltuk
+    Assigned(Seat),
+}
+
+/// Event'in numaralı koltuk bölümü
+///
+/// Bölümdeki her sırada `seats_per_row` koltuk bulunur; tüm koltuklar bölüm
+/// fiyatından satılır.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeatSection {
+    /// Event içindeki bölüm numarası (1'den başlar)
+    pub id: u32,
+    pub name: Symbol,
+    pub rows: u32,
+    pub seats_per_row: u32,
+    pub price: i128,
+    pub sold: u32,
+}
+
+/// Koltuk bölümü oluşturma parametreleri
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeatSectionParams {
+    pub name: Symbol,
+    pub rows: u32,
+    pub seats_per_row: u32,
+    pub price: i128,
+}
+
+/// Sayfalı boş koltuk sorgusu sonucu
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeatPage {
+    pub seats: Vec<Seat>,
+    /// Sonraki sayfa için cursor (taranan koltuk sayısı); son sayfada `None`
+    pub next_cursor: Option<u32>,
+}
+
+/// Event'in bilet kategorisi (GA, VIP, backstage vb.)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketTier {
+    /// Event içindeki kategori numarası (1'den başlar)
+    pub id: u32,
+    pub name: Symbol,
+    pub price: i128,
+    pub capacity: u32,
+    pub sold: u32,
+    /// Kategorinin ek ayrıcalıkları (backstage, lounge vb.) var mı
+    pub has_perks: bool,
+}
+
+/// Bilet kategorisi oluşturma parametreleri
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketTierParams {
+    pub name: Symbol,
+    pub price: i128,
+    pub capacity: u32,
+    pub has_perks: bool,
+}
+
+/// Ticket'ın kullanım durumu
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TicketStatus {
+    /// Kullanılmamış ve refund edilmemiş
+    Active,
+    /// Check-in yapılmış
+    Used,
+    /// Ücreti iade edilmiş
+    Refunded,
+}
+
+/// Ticket listeleme filtresi; boş alanlar filtre uygulanmadığı anlamına gelir
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketFilter {
+    pub event_id: Option<u64>,
+    /// Döndürülecek durumlar
+    pub statuses: Vec<TicketStatus>,
+}
+
+/// Event ticket listesindeki tek bir kayıt
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketSummary {
+    pub id: u64,
+    pub serial: u32,
+    pub owner: Address,
+    pub status: TicketStatus,
+}
+
+/// Sayfalı event ticket sorgusu sonucu
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventTicketPage {
+    pub tickets: Vec<TicketSummary>,
+    /// Sonraki sayfa için cursor (son taranan seri numarası); son sayfada `None`
+    pub next_cursor: Option<u32>,
+}
+
+/// Event'in yaşam döngüsündeki durumu
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EventStatus {
+    /// İptal edilmemiş ve tarihi henüz geçmemiş
+    Active,
+    /// İptal edilmiş
+    Cancelled,
+    /// İptal edilmeden tarihi geçmiş
+    Completed,
+    /// Admin veya guardian tarafından durdurulmuş
+    Paused,
+}
+
+/// Event listeleme filtresi; boş alanlar filtre uygulanmadığı anlamına gelir
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventFilter {
+    /// Döndürülecek durumlar
+    pub statuses: Vec<EventStatus>,
+    pub organizer: Option<Address>,
+    /// `event_date` bu değere eşit veya büyük olmalı
+    pub date_after: Option<u64>,
+    /// `event_date` bu değerden küçük olmalı
+    pub date_before: Option<u64>,
+}
+
+/// Sayfalı event sorgusu sonucu
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventPage {
+    pub events: Vec<Event>,
+    /// Sonraki sayfa için cursor; son sayfada `None`
+    pub next_cursor: Option<u64>,
+}
+
+/// Sayfalı ticket sorgusu sonucu
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketPage {
+    pub tickets: Vec<Ticket>,
+    /// Sonraki sayfa için cursor; son sayfada `None`
+    pub next_cursor: Option<u32>,
 }
 
-/// Contract storage keys
-const EVENTS_KEY: Symbol = symbol_short!("events");
-const TICKETS_KEY: Symbol = symbol_short!("tickets");
-const COUNTER_KEY: Symbol = symbol_short!("counter");
+/// Contract storage keys (instance)
+const EVENT_COUNTER_KEY: Symbol = symbol_short!("ev_cnt");
 const ADMIN_KEY: Symbol = symbol_short!("admin");
-const EVENT_IDS_KEY: Symbol = symbol_short!("event_ids");
+const TOKEN_KEY: Symbol = symbol_short!("token");
+const TICKET_COUNTER_KEY: Symbol = symbol_short!("tkt_cnt");
+const TTL_CONFIG_KEY: Symbol = symbol_short!("ttl_cfg");
+const PENDING_ADMIN_KEY: Symbol = symbol_short!("pend_adm");
+const COUNCIL_KEY: Symbol = symbol_short!("council");
+const PROPOSAL_COUNTER_KEY: Symbol = symbol_short!("prop_cnt");
+const PAUSED_KEY: Symbol = symbol_short!("paused");
+const GUARDIAN_KEY: Symbol = symbol_short!("guardian");
+const HOLD_PERIOD_KEY: Symbol = symbol_short!("rev_hold");
+const FEE_CONFIG_KEY: Symbol = symbol_short!("fee_cfg");
+const TREASURY_KEY: Symbol = symbol_short!("treasury");
+
+/// Kayıt başına tutulan persistent storage anahtarları
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataKey {
+    /// Event kaydı
+    Event(u64),
+    /// Ticket kaydı
+    Ticket(u64),
+    /// Event'in verdiği son ticket seri numarası
+    EventSerial(u64),
+    /// (event, seri numarası) -> ticket ID indeksi
+    EventTicket(u64, u32),
+    /// Adresin sahip olduğu ticket sayısı
+    OwnerTicketCount(Address),
+    /// Adresin ticket indeksinde kullanılan son sıra numarası
+    OwnerTicketSlots(Address),
+    /// (sahip, sıra numarası) -> ticket ID indeksi
+    OwnerTicket(Address, u32),
+    /// Admin tarafından onaylanmış organizatör kaydı
+    Organizer(Address),
+    /// (event, adres) -> event'te check-in yapabilen görevli kaydı
+    Scanner(u64, Address),
+    /// Admin konseyi önerisi
+    CouncilProposal(u64),
+    /// Organizatöre özel platform ücreti
+    OrganizerFee(Address),
+    /// (event, kategori numarası) -> bilet kategorisi
+    EventTier(u64, u32),
+    /// (event, bölüm numarası) -> koltuk bölümü
+    EventSection(u64, u32),
+    /// (event, bölüm, sıra, koltuk) -> koltuğu alan ticket ID'si
+    EventSeat(u64, u32, u32, u32),
+}
+
+/// Tek bir çağrıda işlenecek en fazla refund sayısı
+///
+/// Her refund ticket kaydını ve sahibin token bakiyesini yazar (2 kayıt). Bunlara
+/// event kaydı, contract'ın token bakiyesi, instance storage ve konsey önerisi
+/// eklenir: 4 + 2 * 10 = 24 kayıt, işlem başına 25 yazma sınırının altında kalır.
+const REFUND_BATCH_SIZE: u32 = 10;
+
+/// `bump_event` ile tek çağrıda TTL'i uzatılacak en fazla ticket sayısı
+const BUMP_BATCH_SIZE: u32 = 50;
+
+/// Sayfalı sorgularda tek çağrıda taranacak en fazla kayıt sayısı
+const PAGE_SIZE: u32 = 50;
+
+/// Bir event'e tanımlanabilecek en fazla bilet kategorisi sayısı
+const MAX_TIERS: u32 = 10;
+
+/// Bir event'e tanımlanabilecek en fazla koltuk bölümü sayısı
+const MAX_SECTIONS: u32 = 20;
+
+/// Organizatörün belirleyebileceği en yüksek alım sınırı
+///
+/// Her bilet üç kalıcı kayıt yazar (`Ticket`, `EventTicket`, `OwnerTicket`). Bunlara
+/// alım başına event, `EventSerial`, `OwnerTicketCount`, `OwnerTicketSlots`,
+/// kategori veya bölüm, alıcının ve contract'ın token bakiyesi ve instance storage
+/// eklenir: 8 + 3 * 5 = 23 kayıt, işlem başına 25 yazma sınırının altında kalır.
+const MAX_PER_PURCHASE: u32 = 5;
+
+/// Yeni event'lerde tek satın almada alınabilecek varsayılan bilet sayısı
+const DEFAULT_MAX_PER_PURCHASE: u32 = MAX_PER_PURCHASE;
+
+/// Varsayılan TTL değerleri (ledger cinsinden, ~5 saniyelik ledger'lar)
+const DAY_IN_LEDGERS: u32 = 17280;
+const INSTANCE_EXTEND_TO: u32 = 30 * DAY_IN_LEDGERS;
+const INSTANCE_THRESHOLD: u32 = INSTANCE_EXTEND_TO - DAY_IN_LEDGERS;
+const PERSISTENT_EXTEND_TO: u32 = 60 * DAY_IN_LEDGERS;
+const PERSISTENT_THRESHOLD: u32 = PERSISTENT_EXTEND_TO - 7 * DAY_IN_LEDGERS;
+
+/// Event tarihinden sonra kayıtların canlı tutulduğu varsayılan süre (30 gün, saniye)
+const ARCHIVE_AFTER: u64 = 30 * 24 * 60 * 60;
+
+/// Event tarihinden sonra gelirin itirazlar için bekletildiği varsayılan süre (7 gün, saniye)
+const REVENUE_HOLD_PERIOD: u64 = 7 * 24 * 60 * 60;
+
+/// Storage TTL eşikleri ve arşivleme politikası
+///
+/// Bir kaydın kalan TTL'i `threshold` altına düştüğünde erişimde `extend_to`'ya
+/// uzatılır. Event tarihinden `archive_after` saniye geçtikten sonra event ve
+/// ticket kayıtları artık uzatılmaz ve zamanla arşivlenir.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlConfig {
+    pub instance_threshold: u32,
+    pub instance_extend_to: u32,
+    pub persistent_threshold: u32,
+    pub persistent_extend_to: u32,
+    pub archive_after: u64,
+}
+
+/// Bilet başına kesilen platform ücreti
+///
+/// Ücret `ticket_price * bps / 10000 + flat` olarak hesaplanır ve bilet
+/// fiyatını aşamaz.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeConfig {
+    /// Baz puan cinsinden oran (100 = %1)
+    pub bps: u32,
+    /// Bilet başına sabit ücret
+    pub flat: i128,
+}
 
 /// Event oluşturma parametreleri
 #[contracttype]
@@ -60,75 +460,429 @@ pub struct CreateEventParams {
     pub total_tickets: u32,
     pub ticket_price: i128,
     pub event_date: u64,
+    /// Event ile birlikte oluşturulacak bilet kategorileri
+    pub tiers: Vec<TicketTierParams>,
+}
+
+/// Kabul edilmeyi bekleyen admin devri önerisi
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingAdmin {
+    pub new_admin: Address,
+    /// Önerinin geçerli olduğu son zaman (ledger timestamp); `None` ise süresizdir
+    pub expires_at: Option<u64>,
+}
+
+/// M-of-N admin konseyi
+///
+/// Konsey kurulduktan sonra hassas admin işlemleri yalnızca `threshold` üyenin
+/// onayladığı önerilerle yapılır. Üyeler her değiştiğinde `epoch` artar ve önceki
+/// dönemin bekleyen önerileri geçersiz olur.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Council {
+    pub members: Vec<Address>,
+    pub threshold: u32,
+    pub epoch: u32,
+}
+
+/// Konsey onayıyla yapılabilecek işlemler
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CouncilAction {
+    /// Herhangi bir event'i organizatörden bağımsız olarak iptal eder
+    CancelEvent(u64),
+    /// Admin adresini değiştirir
+    SetAdmin(Address),
+    /// Konsey üyelerini ve onay eşiğini değiştirir
+    SetCouncil(Vec<Address>, u32),
+    /// Birikmiş platform ücretlerini verilen adrese öder
+    WithdrawPlatformFees(Address),
+    /// Gelir bekleme süresini (saniye) değiştirir
+    SetHoldPeriod(u64),
+    /// Varsayılan platform ücretini değiştirir
+    SetPlatformFee(FeeConfig),
+    /// Organizatöre özel platform ücreti tanımlar
+    SetOrganizerFee(Address, FeeConfig),
+    /// Organizatöre özel platform ücretini kaldırır
+    ClearOrganizerFee(Address),
+    /// TTL ayarlarını değiştirir
+    SetTtlConfig(TtlConfig),
+}
+
+/// Onay toplayan konsey önerisi
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CouncilProposal {
+    pub id: u64,
+    pub action: CouncilAction,
+    pub proposer: Address,
+    pub approvals: Vec<Address>,
+    /// Önerinin onaylanabileceği son zaman (ledger timestamp)
+    pub expires_at: u64,
+    /// Önerinin oluşturulduğu konsey dönemi
+    pub epoch: u32,
+    pub executed: bool,
+}
+
+/// Event güncelleme parametreleri
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpdateEventParams {
+    pub title: Symbol,
+    pub description: Symbol,
+    pub total_tickets: u32,
+    pub ticket_price: i128,
+    pub event_date: u64,
 }
 
 /// Ticket satın alma parametreleri
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct BuyTicketParams {
-    pub event_id: Symbol,
+    pub event_id: u64,
     pub buyer: Address,
+    /// Satın alınacak bilet kategorisi; `None` ise standart bilet fiyatı uygulanır
+    pub tier: Option<u32>,
+    /// Satın alınacak koltuk; koltuklu biletlerde bölüm fiyatı uygulanır
+    pub seat: TicketSeat,
+    /// Satın alınacak bilet sayısı; koltuklu alımlarda 1 olmalı
+    pub quantity: u32,
 }
 
 /// Ticket transfer parametreleri
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct TransferTicketParams {
-    pub ticket_id: Symbol,
+    pub ticket_id: u64,
+    pub from: Address,
+    pub to: Address,
+}
+
+/// Yayınlanan contract event'lerinin payload şema sürümü
+///
+/// Payload alanları değiştiğinde artırılır; indexer'lar bu alana göre çözümleme yapar.
+pub const EVENTS_VERSION: u32 = 4;
+
+/// `("event", "created", event_id)` payload'ı
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventCreatedPayload {
+    pub version: u32,
+    pub organizer: Address,
+    pub title: Symbol,
+    pub total_tickets: u32,
+    pub ticket_price: i128,
+    pub event_date: u64,
+}
+
+/// `("event", "updated", event_id)` payload'ı
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventUpdatedPayload {
+    pub version: u32,
+    pub title: Symbol,
+    pub total_tickets: u32,
+    pub ticket_price: i128,
+    pub event_date: u64,
+}
+
+/// `("event", "cancelled", event_id)` payload'ı
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventCancelledPayload {
+    pub version: u32,
+    pub tickets_sold: u32,
+    pub escrow_balance: i128,
+}
+
+/// `("ticket", "sold", ticket_id)` payload'ı
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketSoldPayload {
+    pub version: u32,
+    pub event_id: u64,
+    pub buyer: Address,
+    pub price: i128,
+    pub serial: u32,
+    pub tier: Option<u32>,
+    pub seat: TicketSeat,
+}
+
+/// `("tier", "added", event_id)` payload'ı
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierAddedPayload {
+    pub version: u32,
+    pub tier: TicketTier,
+}
+
+/// `("section", "added", event_id)` payload'ı
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SectionAddedPayload {
+    pub version: u32,
+    pub section: SeatSection,
+}
+
+/// `("ticket", "transferred", ticket_id)` payload'ı
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketTransferredPayload {
+    pub version: u32,
+    pub event_id: u64,
     pub from: Address,
     pub to: Address,
 }
 
+/// `("ticket", "used", ticket_id)` payload'ı
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketUsedPayload {
+    pub version: u32,
+    pub event_id: u64,
+    pub owner: Address,
+    /// Check-in'i yapan adres (organizatör veya görevli)
+    pub scanner: Address,
+}
+
+/// `("ticket", "refunded", ticket_id)` payload'ı
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketRefundedPayload {
+    pub version: u32,
+    pub event_id: u64,
+    pub owner: Address,
+    pub amount: i128,
+}
+
+/// `("organizer", "granted" | "revoked")` payload'ı
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrganizerChangedPayload {
+    pub version: u32,
+    pub organizer: Address,
+}
+
+/// `("scanner", "added" | "removed", event_id)` payload'ı
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScannerChangedPayload {
+    pub version: u32,
+    pub scanner: Address,
+}
+
+/// `("admin", "proposed" | "cancelled")` payload'ı
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminProposalPayload {
+    pub version: u32,
+    pub admin: Address,
+    pub new_admin: Address,
+    pub expires_at: Option<u64>,
+}
+
+/// `("council", "proposed", proposal_id)` payload'ı
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CouncilProposedPayload {
+    pub version: u32,
+    pub proposer: Address,
+    pub action: CouncilAction,
+    pub expires_at: u64,
+}
+
+/// `("council", "approved", proposal_id)` payload'ı
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CouncilApprovedPayload {
+    pub version: u32,
+    pub member: Address,
+    pub approvals: u32,
+}
+
+/// `("council", "executed", proposal_id)` payload'ı
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CouncilExecutedPayload {
+    pub version: u32,
+    pub action: CouncilAction,
+}
+
+/// `("council", "changed")` payload'ı
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CouncilChangedPayload {
+    pub version: u32,
+    pub members: Vec<Address>,
+    pub threshold: u32,
+    pub epoch: u32,
+}
+
+/// `("contract", "paused" | "unpaused")` ve `("event", "paused" | "unpaused", event_id)` payload'ı
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PausePayload {
+    pub version: u32,
+    /// İşlemi yapan admin veya guardian
+    pub by: Address,
+}
+
+/// `("event", "payout", event_id)` payload'ı
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RevenueWithdrawnPayload {
+    pub version: u32,
+    pub organizer: Address,
+    pub amount: i128,
+    /// Event için şimdiye kadar ödenen toplam gelir
+    pub total_withdrawn: i128,
+}
+
+/// `("fee", "changed")` payload'ı; `organizer` yoksa varsayılan ücret değişmiştir
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeChangedPayload {
+    pub version: u32,
+    pub organizer: Option<Address>,
+    pub fee: FeeConfig,
+}
+
+/// `("fee", "cleared")` payload'ı; organizatör artık varsayılan ücreti öder
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeClearedPayload {
+    pub version: u32,
+    pub organizer: Address,
+}
+
+/// `("fee", "withdrawn")` payload'ı
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeesWithdrawnPayload {
+    pub version: u32,
+    pub to: Address,
+    pub amount: i128,
+}
+
+/// `("fee", "settled", event_id)` payload'ı
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeesSettledPayload {
+    pub version: u32,
+    pub amount: i128,
+}
+
+/// `("guardian", "changed")` payload'ı
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GuardianChangedPayload {
+    pub version: u32,
+    pub guardian: Option<Address>,
+}
+
+/// `("admin", "changed")` payload'ı
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminChangedPayload {
+    pub version: u32,
+    pub previous_admin: Option<Address>,
+    pub new_admin: Address,
+}
+
 #[contractimpl]
 impl EventXContract {
-    /// Contract'ı initialize eder ve admin'i ayarlar
-    pub fn initialize(env: &Env, admin: Address) {
-        // Admin'i storage'a kaydet
+    /// Contract'ı initialize eder, admin'i ve ödeme token'ını (SEP-41) ayarlar
+    pub fn initialize(env: &Env, admin: Address, token: Address) -> Result<(), Error> {
+        // Tek seferlik initialize kontrolü
+        if Self::is_initialized(env) {
+            return Err(Error::AlreadyInitialized);
+        }
+        
+        // Admin imza kontrolü
+        admin.require_auth();
+        
+        // Admin'i ve ödeme token'ını storage'a kaydet
         env.storage().instance().set(&ADMIN_KEY, &admin);
+        env.storage().instance().set(&TOKEN_KEY, &token);
+        
+        // Counter'ları sıfırla
+        env.storage().instance().set(&EVENT_COUNTER_KEY, &0u64);
+        env.storage().instance().set(&TICKET_COUNTER_KEY, &0u64);
         
-        // Event ve ticket storage'larını initialize et
-        let events: Map<Symbol, Event> = Map::new(env);
-        let tickets: Map<Symbol, Ticket> = Map::new(env);
-        let event_ids: Vec<Symbol> = vec![env];
+        Self::extend_instance_ttl(env);
         
-        env.storage().instance().set(&EVENTS_KEY, &events);
-        env.storage().instance().set(&TICKETS_KEY, &tickets);
-        env.storage().instance().set(&COUNTER_KEY, &0u32);
-        env.storage().instance().set(&EVENT_IDS_KEY, &event_ids);
+        env.events().publish(
+            (symbol_short!("admin"), symbol_short!("changed")),
+            AdminChangedPayload {
+                version: EVENTS_VERSION,
+                previous_admin: None,
+                new_admin: admin,
+            },
+        );
+        
+        Ok(())
+    }
+
+    /// Contract'ın initialize edilip edilmediğini döndürür
+    pub fn is_initialized(env: &Env) -> bool {
+        env.storage().instance().has(&ADMIN_KEY)
+    }
+
+    /// Contract initialize edilmemişse `NotInitialized` hatası verir
+    fn require_initialized(env: &Env) -> Result<(), Error> {
+        if !Self::is_initialized(env) {
+            return Err(Error::NotInitialized);
+        }
+        Ok(())
     }
 
-    /// Admin kontrolü yapar
-    fn require_admin(env: &Env, caller: &Address) {
-        let admin: Address = env.storage().instance().get(&ADMIN_KEY).unwrap();
+    /// Admin kontrolü yapar ve admin imzasını ister
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&ADMIN_KEY)
+            .ok_or(Error::NotInitialized)?;
         if caller != &admin {
-            panic!("Sadece admin bu işlemi yapabilir");
+            return Err(Error::Unauthorized);
+        }
+        caller.require_auth();
+        Ok(())
+    }
+
+    /// Organizatörün event'inde yetki kontrolü yapar ve organizatör imzasını ister
+    fn require_organizer(event: &Event, caller: &Address) -> Result<(), Error> {
+        if caller != &event.organizer {
+            return Err(Error::Unauthorized);
         }
+        caller.require_auth();
+        Ok(())
     }
 
-    /// Event oluşturur (sadece admin)
-    pub fn create_event(env: &Env, caller: Address, params: CreateEventParams) -> Symbol {
-        // Admin kontrolü
-        Self::require_admin(env, &caller);
+    /// Event oluşturur (admin veya onaylı organizatör); çağıran event'in organizatörü olur
+    pub fn create_event(env: &Env, caller: Address, params: CreateEventParams) -> Result<u64, Error> {
+        Self::require_initialized(env)?;
         
-        // Event ID oluştur
-        let event_counter: u32 = env.storage().instance().get(&COUNTER_KEY).unwrap();
-        let event_id = match event_counter {
-            0 => symbol_short!("event_0"),
-            1 => symbol_short!("event_1"),
-            2 => symbol_short!("event_2"),
-            3 => symbol_short!("event_3"),
-            4 => symbol_short!("event_4"),
-            5 => symbol_short!("event_5"),
-            6 => symbol_short!("event_6"),
-            7 => symbol_short!("event_7"),
-            8 => symbol_short!("event_8"),
-            9 => symbol_short!("event_9"),
-            _ => symbol_short!("event_x"),
-        };
+        // Organizatör kontrolü
+        if caller != Self::get_admin(env)? && !Self::organizer_registered(env, &caller) {
+            return Err(Error::Unauthorized);
+        }
+        caller.require_auth();
+        Self::extend_instance_ttl(env);
+        
+        // Fiyat kontrolü
+        if params.ticket_price < 0 {
+            return Err(Error::InvalidPrice);
+        }
+        
+        // Event ID oluştur (1'den başlayan, tekrar etmeyen sıra numarası)
+        let event_counter = Self::event_count(env)?;
+        let event_id = event_counter + 1;
         
         // Event'i oluştur
-        let event = Event {
-            id: event_id.clone(),
+        let mut event = Event {
+            id: event_id,
             title: params.title,
             description: params.description,
             organizer: caller,
@@ -138,147 +892,668 @@ impl EventXContract {
             event_date: params.event_date,
             is_active: true,
             is_cancelled: false,
+            is_paused: false,
+            escrow_balance: 0,
+            refund_cursor: 0,
+            revenue_withdrawn: 0,
+            platform_fees: 0,
+            fees_pending: 0,
+            used_revenue: 0,
+            tier_count: 0,
+            section_count: 0,
+            max_per_purchase: DEFAULT_MAX_PER_PURCHASE,
         };
         
         // Event'i storage'a kaydet
-        let mut events: Map<Symbol, Event> = env.storage().instance().get(&EVENTS_KEY).unwrap();
-        events.set(event_id.clone(), event);
-        env.storage().instance().set(&EVENTS_KEY, &events);
+        Self::save_event(env, &event);
         
-        // Event ID'yi listeye ekle
-        let mut event_ids: Vec<Symbol> = env.storage().instance().get(&EVENT_IDS_KEY).unwrap();
-        event_ids.push_back(event_id.clone());
-        env.storage().instance().set(&EVENT_IDS_KEY, &event_ids);
+        // Event counter'ı artır (ID'ler oluşturulma sırasını korur)
+        env.storage().instance().set(&EVENT_COUNTER_KEY, &event_id);
         
-        // Event counter'ı artır
-        env.storage().instance().set(&COUNTER_KEY, &(event_counter + 1));
+        env.events().publish(
+            (symbol_short!("event"), symbol_short!("created"), event_id),
+            EventCreatedPayload {
+                version: EVENTS_VERSION,
+                organizer: event.organizer.clone(),
+                title: event.title.clone(),
+                total_tickets: event.total_tickets,
+                ticket_price: event.ticket_price,
+                event_date: event.event_date,
+            },
+        );
+        
+        // Bilet kategorilerini ekle
+        for tier_params in params.tiers.iter() {
+            Self::add_tier(env, &mut event, tier_params)?;
+        }
         
-        event_id
+        Ok(event_id)
     }
 
-    /// Event için ticket satın alır ve NFT mint eder
-    pub fn buy_ticket(env: &Env, caller: Address, params: BuyTicketParams) -> Symbol {
-        // Event'i kontrol et
-        let events: Map<Symbol, Event> = env.storage().instance().get(&EVENTS_KEY).unwrap();
-        let event_option = events.get(params.event_id.clone());
+    /// Event'e yeni bilet kategorisi ekler (sadece event organizatörü)
+    ///
+    /// Kategori kapasitesi event'in toplam bilet sayısından bağımsız olarak
+    /// uygulanır; toplam bilet sayısı tüm kategoriler için üst sınır olarak kalır.
+    /// Oluşturulan kategori numarasını döndürür.
+    pub fn add_ticket_tier(
+        env: &Env,
+        caller: Address,
+        event_id: u64,
+        params: TicketTierParams,
+    ) -> Result<u32, Error> {
+        Self::require_initialized(env)?;
+        Self::extend_instance_ttl(env);
         
-        // Event var mı kontrol et
-        if event_option.is_none() {
-            panic!("Event bulunamadı");
-        }
+        let mut event = Self::load_event(env, event_id).ok_or(Error::EventNotFound)?;
         
-        let event = event_option.unwrap();
+        // Organizatör kontrolü
+        Self::require_organizer(&event, &caller)?;
         
-        // Event aktif mi kontrol et
-        if !event.is_active || event.is_cancelled {
-            panic!("Event aktif değil veya iptal edilmiş");
+        if event.is_cancelled {
+            return Err(Error::EventCancelled);
         }
         
-        // Bilet satış limiti kontrol et
-        if event.tickets_sold >= event.total_tickets {
-            panic!("Event için tüm biletler satılmış");
-        }
+        Self::add_tier(env, &mut event, params)
+    }
+
+    /// Event'in bilet kategorisini döndürür
+    pub fn get_ticket_tier(env: &Env, event_id: u64, tier_id: u32) -> Result<TicketTier, Error> {
+        Self::require_initialized(env)?;
+        Self::load_tier(env, event_id, tier_id).ok_or(Error::TierNotFound)
+    }
+
+    /// Event'in tüm bilet kategorilerini döndürür
+    pub fn get_ticket_tiers(env: &Env, event_id: u64) -> Result<Vec<TicketTier>, Error> {
+        Self::require_initialized(env)?;
         
-        // Ticket ID oluştur
-        let ticket_counter: u32 = env.storage().instance().get(&COUNTER_KEY).unwrap();
-        let ticket_id = symbol_short!("ticket");
+        let event = Self::load_event(env, event_id).ok_or(Error::EventNotFound)?;
         
-        // Ticket'ı oluştur
-        let ticket = Ticket {
-            id: ticket_id.clone(),
-            event_id: params.event_id.clone(),
-            owner: params.buyer,
-            is_used: false,
-            is_refunded: false,
-            purchase_date: env.ledger().timestamp(),
-        };
+        let mut result = Vec::new(env);
+        for tier_id in 1..=event.tier_count {
+            if let Some(tier) = Self::load_tier(env, event_id, tier_id) {
+                result.push_back(tier);
+            }
+        }
+        
+        Ok(result)
+    }
+
+    /// Event'e numaralı koltuk bölümü ekler (sadece event organizatörü)
+    ///
+    /// Bölüm koltukları event'in toplam bilet sayısına dahildir. Oluşturulan
+    /// bölüm numarasını döndürür.
+    pub fn add_seat_section(
+        env: &Env,
+        caller: Address,
+        event_id: u64,
+        params: SeatSectionParams,
+    ) -> Result<u32, Error> {
+        Self::require_initialized(env)?;
+        Self::extend_instance_ttl(env);
+        
+        let mut event = Self::load_event(env, event_id).ok_or(Error::EventNotFound)?;
+        
+        // Organizatör kontrolü
+        Self::require_organizer(&event, &caller)?;
+        
+        if event.is_cancelled {
+            return Err(Error::EventCancelled);
+        }
+        if params.price < 0 {
+            return Err(Error::InvalidPrice);
+        }
+        if params.rows == 0
+            || params.seats_per_row == 0
+            || params.rows.checked_mul(params.seats_per_row).is_none()
+            || event.section_count >= MAX_SECTIONS
+        {
+            return Err(Error::InvalidSection);
+        }
+        
+        event.section_count += 1;
+        let section = SeatSection {
+            id: event.section_count,
+            name: params.name,
+            rows: params.rows,
+            seats_per_row: params.seats_per_row,
+            price: params.price,
+            sold: 0,
+        };
+        Self::save_section(env, &event, &section);
+        Self::save_event(env, &event);
+        
+        env.events().publish(
+            (symbol_short!("section"), symbol_short!("added"), event_id),
+            SectionAddedPayload {
+                version: EVENTS_VERSION,
+                section,
+            },
+        );
+        
+        Ok(event.section_count)
+    }
+
+    /// Event'in koltuk bölümünü döndürür
+    pub fn get_seat_section(
+        env: &Env,
+        event_id: u64,
+        section_id: u32,
+    ) -> Result<SeatSection, Error> {
+        Self::require_initialized(env)?;
+        Self::load_section(env, event_id, section_id).ok_or(Error::SectionNotFound)
+    }
+
+    /// Event'in tüm koltuk bölümlerini döndürür
+    pub fn get_seat_sections(env: &Env, event_id: u64) -> Result<Vec<SeatSection>, Error> {
+        Self::require_initialized(env)?;
+        
+        let event = Self::load_event(env, event_id).ok_or(Error::EventNotFound)?;
+        
+        let mut result = Vec::new(env);
+        for section_id in 1..=event.section_count {
+            if let Some(section) = Self::load_section(env, event_id, section_id) {
+                result.push_back(section);
+            }
+        }
+        
+        Ok(result)
+    }
+
+    /// Bölümdeki boş koltukları sıra ve koltuk numarası sırasıyla sayfa sayfa döndürür
+    ///
+    /// `cursor` taranan koltuk sayısıdır; `cursor`'dan sonraki en fazla `limit`
+    /// koltuk taranır. İlk sayfa için `cursor` 0 verilir.
+    pub fn get_available_seats(
+        env: &Env,
+        event_id: u64,
+        section_id: u32,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<SeatPage, Error> {
+        Self::require_initialized(env)?;
+        
+        let section = Self::load_section(env, event_id, section_id)
+            .ok_or(Error::SectionNotFound)?;
+        
+        let total = section.rows * section.seats_per_row;
+        let limit = if limit == 0 || limit > PAGE_SIZE {
+            PAGE_SIZE
+        } else {
+            limit
+        };
+        let end = if total.saturating_sub(cursor) > limit {
+            cursor + limit
+        } else {
+            total
+        };
+        
+        let mut seats = Vec::new(env);
+        for index in cursor..end {
+            let seat = Seat {
+                section: section_id,
+                row: index / section.seats_per_row + 1,
+                number: index % section.seats_per_row + 1,
+            };
+            if !env.storage().persistent().has(&Self::seat_key(event_id, &seat)) {
+                seats.push_back(seat);
+            }
+        }
+        
+        Ok(SeatPage {
+            seats,
+            next_cursor: if end < total { Some(end) } else { None },
+        })
+    }
+
+    /// Koltuğun bölümde bulunduğunu ve satılmadığını doğrular, bölümü döndürür
+    fn require_free_seat(env: &Env, event_id: u64, seat: &Seat) -> Result<SeatSection, Error> {
+        let section = Self::load_section(env, event_id, seat.section)
+            .ok_or(Error::SectionNotFound)?;
+        if seat.row == 0
+            || seat.row > section.rows
+            || seat.number == 0
+            || seat.number > section.seats_per_row
+        {
+            return Err(Error::SeatNotFound);
+        }
+        if env.storage().persistent().has(&Self::seat_key(event_id, seat)) {
+            return Err(Error::SeatTaken);
+        }
+        Ok(section)
+    }
+
+    /// Koltuk rezervasyonunun storage anahtarı
+    fn seat_key(event_id: u64, seat: &Seat) -> DataKey {
+        DataKey::EventSeat(event_id, seat.section, seat.row, seat.number)
+    }
+
+    /// Kategoriyi doğrulayıp event'e ekler ve numarasını döndürür
+    fn add_tier(env: &Env, event: &mut Event, params: TicketTierParams) -> Result<u32, Error> {
+        if params.price < 0 {
+            return Err(Error::InvalidPrice);
+        }
+        if params.capacity == 0 || event.tier_count >= MAX_TIERS {
+            return Err(Error::InvalidTier);
+        }
+        
+        event.tier_count += 1;
+        let tier = TicketTier {
+            id: event.tier_count,
+            name: params.name,
+            price: params.price,
+            capacity: params.capacity,
+            sold: 0,
+            has_perks: params.has_perks,
+        };
+        Self::save_tier(env, event, &tier);
+        Self::save_event(env, event);
+        
+        env.events().publish(
+            (symbol_short!("tier"), symbol_short!("added"), event.id),
+            TierAddedPayload {
+                version: EVENTS_VERSION,
+                tier,
+            },
+        );
+        
+        Ok(event.tier_count)
+    }
+
+    /// Event bilgilerini günceller (sadece event organizatörü)
+    ///
+    /// Toplam bilet sayısı satılan bilet sayısının altına düşürülemez. Fiyat
+    /// değişikliği yalnızca sonraki satışları etkiler; refund'lar ödenen tutar üzerinden yapılır.
+    /// Bilet satıldıktan sonra event tarihi yalnızca ileri alınabilir; aksi halde
+    /// gelir bekleme süresi kısaltılmış olur.
+    pub fn update_event(
+        env: &Env,
+        caller: Address,
+        event_id: u64,
+        params: UpdateEventParams,
+    ) -> Result<bool, Error> {
+        Self::require_initialized(env)?;
+        Self::extend_instance_ttl(env);
+        
+        let mut event = Self::load_event(env, event_id).ok_or(Error::EventNotFound)?;
+        
+        // Organizatör kontrolü
+        Self::require_organizer(&event, &caller)?;
+        
+        if event.is_cancelled {
+            return Err(Error::EventCancelled);
+        }
+        
+        if params.ticket_price < 0 {
+            return Err(Error::InvalidPrice);
+        }
+        
+        if params.total_tickets < event.tickets_sold {
+            return Err(Error::InvalidTicketSupply);
+        }
+        
+        if event.tickets_sold > 0 && params.event_date < event.event_date {
+            return Err(Error::InvalidEventDate);
+        }
+        
+        event.title = params.title;
+        event.description = params.description;
+        event.total_tickets = params.total_tickets;
+        event.ticket_price = params.ticket_price;
+        event.event_date = params.event_date;
+        Self::save_event(env, &event);
+        
+        env.events().publish(
+            (symbol_short!("event"), symbol_short!("updated"), event_id),
+            EventUpdatedPayload {
+                version: EVENTS_VERSION,
+                title: event.title,
+                total_tickets: event.total_tickets,
+                ticket_price: event.ticket_price,
+                event_date: event.event_date,
+            },
+        );
         
-        // Ticket'ı storage'a kaydet
-        let mut tickets: Map<Symbol, Ticket> = env.storage().instance().get(&TICKETS_KEY).unwrap();
-        tickets.set(ticket_id.clone(), ticket);
-        env.storage().instance().set(&TICKETS_KEY, &tickets);
+        Ok(true)
+    }
+
+    /// Event için `quantity` adet ticket satın alır, ücreti escrow'a çeker ve NFT mint eder
+    ///
+    /// Toplam ücret tek bir transferle çekilir. Kalan kapasite yetmezse hiçbir
+    /// ticket oluşturulmaz. Satış `event_date` anında kapanır. Oluşturulan
+    /// ticket ID'lerini döndürür.
+    pub fn buy_ticket(
+        env: &Env,
+        caller: Address,
+        params: BuyTicketParams,
+    ) -> Result<Vec<u64>, Error> {
+        Self::require_initialized(env)?;
+        
+        // Alıcı kontrolü (ücreti alıcı öder, bu yüzden alıcı imzalamalı)
+        if caller != params.buyer {
+            return Err(Error::Unauthorized);
+        }
+        caller.require_auth();
+        Self::require_not_paused(env)?;
+        Self::extend_instance_ttl(env);
+        
+        // Event var mı kontrol et
+        let mut event = Self::load_event(env, params.event_id).ok_or(Error::EventNotFound)?;
+        
+        // Event aktif mi kontrol et
+        if event.is_cancelled {
+            return Err(Error::EventCancelled);
+        }
+        if !event.is_active {
+            return Err(Error::EventNotActive);
+        }
+        if event.is_paused {
+            return Err(Error::EventPaused);
+        }
+        
+        // Event başladıktan sonra satılan biletler gelir bekleme süresini atlardı
+        if env.ledger().timestamp() >= event.event_date {
+            return Err(Error::SalesClosed);
+        }
+        
+        // Bilet adedi kontrolü
+        let quantity = params.quantity;
+        if quantity == 0 || quantity > event.max_per_purchase {
+            return Err(Error::InvalidQuantity);
+        }
+        if quantity > 1 && params.seat != TicketSeat::Unassigned {
+            return Err(Error::InvalidQuantity);
+        }
+        
+        // Bilet satış limiti kontrol et
+        if event.total_tickets.saturating_sub(event.tickets_sold) < quantity {
+            return Err(Error::SoldOut);
+        }
+        
+        // Koltuklu biletin fiyatı bölümden gelir, kategori seçilemez
+        if params.tier.is_some() && params.seat != TicketSeat::Unassigned {
+            return Err(Error::InvalidTier);
+        }
+        
+        // Kategori seçildiyse kategori fiyatı ve kapasitesi uygulanır
+        let mut tier = match params.tier {
+            Some(tier_id) => {
+                let tier = Self::load_tier(env, params.event_id, tier_id)
+                    .ok_or(Error::TierNotFound)?;
+                if tier.capacity.saturating_sub(tier.sold) < quantity {
+                    return Err(Error::TierSoldOut);
+                }
+                Some(tier)
+            }
+            None => None,
+        };
+        
+        // Koltuk seçildiyse koltuğun boş olduğu kontrol edilir ve bölüm fiyatı uygulanır
+        let mut section = match &params.seat {
+            TicketSeat::Assigned(seat) => {
+                Some(Self::require_free_seat(env, params.event_id, seat)?)
+            }
+            TicketSeat::Unassigned => None,
+        };
+        let price = match (&tier, &section) {
+            (Some(tier), _) => tier.price,
+            (None, Some(section)) => section.price,
+            (None, None) => event.ticket_price,
+        };
+        
+        // Organizatörün bilet başına platform ücretini hesapla
+        let fee = Self::ticket_fee(env, &event.organizer, price)?;
+        
+        // Toplam tutarlar taşarsa satış reddedilir (ücret fiyatı aşamaz)
+        let total_price = price
+            .checked_mul(quantity as i128)
+            .ok_or(Error::InvalidPrice)?;
+        let total_fee = fee * quantity as i128;
+        let escrow_balance = event
+            .escrow_balance
+            .checked_add(total_price - total_fee)
+            .ok_or(Error::InvalidPrice)?;
+        let platform_fees = event
+            .platform_fees
+            .checked_add(total_fee)
+            .ok_or(Error::InvalidPrice)?;
+        let fees_pending = event
+            .fees_pending
+            .checked_add(total_fee)
+            .ok_or(Error::InvalidPrice)?;
+        
+        // Tüm biletlerin ücretini alıcıdan tek transferle contract'a çek
+        if total_price > 0 {
+            Self::token_client(env)?.transfer(
+                &params.buyer,
+                &env.current_contract_address(),
+                &total_price,
+            );
+        }
+        
+        // Ticket ID'leri ve event içi seri numaraları ardışık verilir
+        let first_id = Self::ticket_count(env)? + 1;
+        let first_serial = Self::last_serial(env, params.event_id) + 1;
+        
+        let mut ticket_ids = Vec::new(env);
+        for offset in 0..quantity {
+            let ticket_id = first_id + offset as u64;
+            let serial = first_serial + offset;
+            
+            // Ticket'ı alıcının ticket indeksine ekle
+            let owner_slot = Self::add_owner_ticket(env, &params.buyer, ticket_id);
+            
+            // Ticket'ı oluştur
+            let ticket = Ticket {
+                id: ticket_id,
+                event_id: params.event_id,
+                owner: params.buyer.clone(),
+                is_used: false,
+                is_refunded: false,
+                purchase_date: env.ledger().timestamp(),
+                price_paid: price,
+                fee_paid: fee,
+                serial,
+                owner_slot,
+                tier: params.tier,
+                seat: params.seat.clone(),
+            };
+            
+            // Ticket'ı event'in ticket indeksine ekle
+            env.storage()
+                .persistent()
+                .set(&DataKey::EventTicket(params.event_id, serial), &ticket_id);
+            
+            // Koltuğu ticket'a ayır
+            if let TicketSeat::Assigned(seat) = &ticket.seat {
+                env.storage()
+                    .persistent()
+                    .set(&Self::seat_key(params.event_id, seat), &ticket_id);
+            }
+            
+            // Ticket'ı storage'a kaydet
+            Self::save_ticket(env, &ticket, &event);
+            
+            env.events().publish(
+                (symbol_short!("ticket"), symbol_short!("sold"), ticket_id),
+                TicketSoldPayload {
+                    version: EVENTS_VERSION,
+                    event_id: ticket.event_id,
+                    buyer: ticket.owner,
+                    price: ticket.price_paid,
+                    serial,
+                    tier: ticket.tier,
+                    seat: ticket.seat,
+                },
+            );
+            
+            ticket_ids.push_back(ticket_id);
+        }
+        
+        let last_id = first_id + quantity as u64 - 1;
+        env.storage()
+            .persistent()
+            .set(&DataKey::EventSerial(params.event_id), &(first_serial + quantity - 1));
         
         // Event satış sayısını güncelle
-        let mut updated_event = event;
-        updated_event.tickets_sold += 1;
-        let mut updated_events = events;
-        updated_events.set(params.event_id, updated_event);
-        env.storage().instance().set(&EVENTS_KEY, &updated_events);
+        event.tickets_sold += quantity;
+        event.escrow_balance = escrow_balance;
+        event.platform_fees = platform_fees;
+        event.fees_pending = fees_pending;
+        Self::save_event(env, &event);
+        
+        // Kategori satış sayısını güncelle
+        if let Some(tier) = tier.as_mut() {
+            tier.sold += quantity;
+            Self::save_tier(env, &event, tier);
+        }
+        
+        // Bölüm satış sayısını güncelle
+        if let Some(section) = section.as_mut() {
+            section.sold += 1;
+            Self::save_section(env, &event, section);
+        }
         
         // Ticket counter'ı artır
-        env.storage().instance().set(&COUNTER_KEY, &(ticket_counter + 1));
+        env.storage().instance().set(&TICKET_COUNTER_KEY, &last_id);
+        
+        Ok(ticket_ids)
+    }
+
+    /// Tek satın almada alınabilecek en fazla bilet sayısını ayarlar (sadece event organizatörü)
+    pub fn set_purchase_limit(
+        env: &Env,
+        caller: Address,
+        event_id: u64,
+        max_per_purchase: u32,
+    ) -> Result<bool, Error> {
+        Self::require_initialized(env)?;
+        Self::extend_instance_ttl(env);
+        
+        let mut event = Self::load_event(env, event_id).ok_or(Error::EventNotFound)?;
+        
+        // Organizatör kontrolü
+        Self::require_organizer(&event, &caller)?;
+        
+        if max_per_purchase == 0 || max_per_purchase > MAX_PER_PURCHASE {
+            return Err(Error::InvalidQuantity);
+        }
+        
+        event.max_per_purchase = max_per_purchase;
+        Self::save_event(env, &event);
         
-        ticket_id
+        Ok(true)
     }
 
     /// Ticket sahipliğini transfer eder
-    pub fn transfer_ticket(env: &Env, _caller: Address, params: TransferTicketParams) -> bool {
+    pub fn transfer_ticket(
+        env: &Env,
+        caller: Address,
+        params: TransferTicketParams,
+    ) -> Result<bool, Error> {
+        Self::require_initialized(env)?;
+        
+        // Sadece ticket sahibi kendi ticket'ını transfer edebilir
+        if caller != params.from {
+            return Err(Error::NotOwner);
+        }
+        caller.require_auth();
+        Self::require_not_paused(env)?;
+        Self::extend_instance_ttl(env);
+        
         // Ticket'ı kontrol et
-        let mut tickets: Map<Symbol, Ticket> = env.storage().instance().get(&TICKETS_KEY).unwrap();
-        let ticket = tickets.get(params.ticket_id.clone()).unwrap();
+        let ticket = Self::load_ticket(env, params.ticket_id).ok_or(Error::TicketNotFound)?;
         
         // Sahiplik kontrolü
         if ticket.owner != params.from {
-            panic!("Ticket sahibi değilsiniz");
+            return Err(Error::NotOwner);
         }
         
         // Ticket kullanılmış mı kontrol et
         if ticket.is_used {
-            panic!("Kullanılmış ticket transfer edilemez");
+            return Err(Error::AlreadyUsed);
         }
         
         // Refund edilmiş mi kontrol et
         if ticket.is_refunded {
-            panic!("Refund edilmiş ticket transfer edilemez");
+            return Err(Error::AlreadyRefunded);
         }
         
         // Event'i kontrol et
-        let events: Map<Symbol, Event> = env.storage().instance().get(&EVENTS_KEY).unwrap();
-        let event = events.get(ticket.event_id.clone()).unwrap();
+        let event = Self::load_event(env, ticket.event_id).ok_or(Error::EventNotFound)?;
         
-        // Event iptal edilmiş mi kontrol et
+        // Event iptal edilmiş veya durdurulmuş mu kontrol et
         if event.is_cancelled {
-            panic!("İptal edilmiş event'in ticket'ı transfer edilemez");
+            return Err(Error::EventCancelled);
         }
+        if event.is_paused {
+            return Err(Error::EventPaused);
+        }
+        
+        // Ticket'ı eski sahibin indeksinden yeni sahibin indeksine taşı
+        Self::remove_owner_ticket(env, &ticket.owner, ticket.owner_slot);
+        let owner_slot = Self::add_owner_ticket(env, &params.to, ticket.id);
         
         // Ticket'ı güncelle
         let updated_ticket = Ticket {
-            owner: params.to,
+            owner: params.to.clone(),
+            owner_slot,
             ..ticket
         };
         
-        tickets.set(params.ticket_id, updated_ticket);
-        env.storage().instance().set(&TICKETS_KEY, &tickets);
+        Self::save_ticket(env, &updated_ticket, &event);
+        Self::extend_event_ttl(env, &event);
         
-        true
+        env.events().publish(
+            (
+                symbol_short!("ticket"),
+                Symbol::new(env, "transferred"),
+                params.ticket_id,
+            ),
+            TicketTransferredPayload {
+                version: EVENTS_VERSION,
+                event_id: event.id,
+                from: params.from,
+                to: params.to,
+            },
+        );
+        
+        Ok(true)
     }
 
     /// Ticket'ı kullanıldı olarak işaretler (check-in)
-    pub fn use_ticket(env: &Env, caller: Address, ticket_id: Symbol) -> bool {
-        // Admin kontrolü (sadece event organizatörü veya admin check-in yapabilir)
-        Self::require_admin(env, &caller);
+    ///
+    /// Sadece event organizatörü veya event'e atanmış görevliler check-in yapabilir.
+    pub fn use_ticket(env: &Env, caller: Address, ticket_id: u64) -> Result<bool, Error> {
+        Self::require_initialized(env)?;
+        Self::extend_instance_ttl(env);
         
         // Ticket'ı kontrol et
-        let mut tickets: Map<Symbol, Ticket> = env.storage().instance().get(&TICKETS_KEY).unwrap();
-        let ticket = tickets.get(ticket_id.clone()).unwrap();
+        let ticket = Self::load_ticket(env, ticket_id).ok_or(Error::TicketNotFound)?;
+        
+        // Görevli kontrolü
+        let event = Self::load_event(env, ticket.event_id).ok_or(Error::EventNotFound)?;
+        if caller != event.organizer && !Self::scanner_registered(env, event.id, &caller) {
+            return Err(Error::Unauthorized);
+        }
+        caller.require_auth();
+        Self::require_not_paused(env)?;
         
         // Ticket zaten kullanılmış mı kontrol et
         if ticket.is_used {
-            panic!("Ticket zaten kullanılmış");
+            return Err(Error::AlreadyUsed);
         }
         
         // Refund edilmiş mi kontrol et
         if ticket.is_refunded {
-            panic!("Refund edilmiş ticket kullanılamaz");
+            return Err(Error::AlreadyRefunded);
         }
         
-        // Event'i kontrol et
-        let events: Map<Symbol, Event> = env.storage().instance().get(&EVENTS_KEY).unwrap();
-        let event = events.get(ticket.event_id.clone()).unwrap();
-        
-        // Event iptal edilmiş mi kontrol et
+        // Event iptal edilmiş veya durdurulmuş mu kontrol et
         if event.is_cancelled {
-            panic!("İptal edilmiş event'in ticket'ı kullanılamaz");
+            return Err(Error::EventCancelled);
+        }
+        if event.is_paused {
+            return Err(Error::EventPaused);
         }
         
         // Ticket'ı kullanıldı olarak işaretle
@@ -287,453 +1562,6224 @@ impl EventXContract {
             ..ticket
         };
         
-        tickets.set(ticket_id, updated_ticket);
-        env.storage().instance().set(&TICKETS_KEY, &tickets);
+        Self::save_ticket(env, &updated_ticket, &event);
+        Self::extend_event_ttl(env, &event);
+        
+        env.events().publish(
+            (symbol_short!("ticket"), symbol_short!("used"), ticket_id),
+            TicketUsedPayload {
+                version: EVENTS_VERSION,
+                event_id: event.id,
+                owner: updated_ticket.owner,
+                scanner: caller,
+            },
+        );
         
-        true
+        Ok(true)
     }
 
-    /// Event'i iptal eder ve tüm ticket'ları refund eder
-    pub fn cancel_event(env: &Env, caller: Address, event_id: Symbol) -> bool {
-        // Admin kontrolü
-        Self::require_admin(env, &caller);
+    /// Event'i iptal eder ve en fazla `limit` ticket'lık ilk refund batch'ini işler
+    /// (sadece event organizatörü)
+    ///
+    /// `limit` 0 veya `REFUND_BATCH_SIZE`'dan büyükse batch boyutu kullanılır.
+    /// Kalan ticket'lar `process_refunds` ile parça parça refund edilir.
+    pub fn cancel_event(
+        env: &Env,
+        caller: Address,
+        event_id: u64,
+        limit: u32,
+    ) -> Result<bool, Error> {
+        Self::require_initialized(env)?;
+        Self::extend_instance_ttl(env);
         
         // Event'i kontrol et
-        let mut events: Map<Symbol, Event> = env.storage().instance().get(&EVENTS_KEY).unwrap();
-        let event = events.get(event_id.clone()).unwrap();
+        let event = Self::load_event(env, event_id).ok_or(Error::EventNotFound)?;
         
+        // Organizatör kontrolü
+        Self::require_organizer(&event, &caller)?;
+        
+        Self::cancel(env, event, Self::refund_limit(limit))?;
+        
+        Ok(true)
+    }
+
+    /// Event'i iptal edildi olarak işaretler ve en fazla `limit` ticket'ı refund eder
+    fn cancel(env: &Env, mut event: Event, limit: u32) -> Result<(), Error> {
         // Event zaten iptal edilmiş mi kontrol et
         if event.is_cancelled {
-            panic!("Event zaten iptal edilmiş");
+            return Err(Error::EventCancelled);
+        }
+        
+        // Ödenmiş gelir ve hazineye aktarılmış ücretler refund'lar için escrow'da değil
+        if event.revenue_withdrawn > 0 || event.fees_pending < event.platform_fees {
+            return Err(Error::RevenueWithdrawn);
         }
         
         // Event'i iptal et
-        let mut updated_event = event;
-        updated_event.is_cancelled = true;
-        updated_event.is_active = false;
-        events.set(event_id.clone(), updated_event);
-        env.storage().instance().set(&EVENTS_KEY, &events);
+        event.is_cancelled = true;
+        event.is_active = false;
+        Self::save_event(env, &event);
+        
+        env.events().publish(
+            (symbol_short!("event"), symbol_short!("cancelled"), event.id),
+            EventCancelledPayload {
+                version: EVENTS_VERSION,
+                tickets_sold: event.tickets_sold,
+                escrow_balance: event.escrow_balance,
+            },
+        );
+        
+        // İlk refund batch'ini işle
+        Self::refund_batch(env, event.id, limit)?;
+        
+        Ok(())
+    }
+
+    /// İptal edilmiş event'in bekleyen refund'larından en fazla `limit` tanesini işler
+    ///
+    /// Herkes çağırabilir; ödemeler her zaman ticket sahibine yapılır.
+    /// Geriye kalan, henüz işlenmemiş ticket sayısını döndürür.
+    pub fn process_refunds(env: &Env, event_id: u64, limit: u32) -> Result<u32, Error> {
+        Self::require_initialized(env)?;
+        Self::extend_instance_ttl(env);
+        
+        let event = Self::load_event(env, event_id).ok_or(Error::EventNotFound)?;
+        
+        if !event.is_cancelled {
+            return Err(Error::EventNotCancelled);
+        }
+        
+        Self::refund_batch(env, event_id, Self::refund_limit(limit))
+    }
+
+    /// 0 veya batch boyutunu aşan limitleri `REFUND_BATCH_SIZE`'a indirir
+    fn refund_limit(limit: u32) -> u32 {
+        if limit == 0 || limit > REFUND_BATCH_SIZE {
+            REFUND_BATCH_SIZE
+        } else {
+            limit
+        }
+    }
+
+    /// Event'in henüz refund için işlenmemiş ticket sayısını döndürür
+    pub fn get_pending_refunds(env: &Env, event_id: u64) -> Result<u32, Error> {
+        Self::require_initialized(env)?;
+        
+        let event = Self::load_event(env, event_id).ok_or(Error::EventNotFound)?;
+        
+        if !event.is_cancelled {
+            return Ok(0);
+        }
+        
+        Ok(Self::event_ticket_serial(env, event_id)? - event.refund_cursor)
+    }
+
+    /// `refund_cursor`'dan itibaren en fazla `limit` ticket'ı refund eder
+    fn refund_batch(env: &Env, event_id: u64, limit: u32) -> Result<u32, Error> {
+        let mut event = Self::load_event(env, event_id).ok_or(Error::EventNotFound)?;
+        let total = Self::event_ticket_serial(env, event_id)?;
+        
+        let start = event.refund_cursor;
+        let end = if total - start > limit {
+            start + limit
+        } else {
+            total
+        };
+        
+        // Seri numaraları 1'den başlar
+        for serial in (start + 1)..=end {
+            let ticket = match Self::event_ticket_id(env, event_id, serial)
+                .and_then(|ticket_id| Self::load_ticket(env, ticket_id))
+            {
+                Some(ticket) => ticket,
+                None => continue,
+            };
+            
+            // Refund edilmiş ticket'ları atla
+            if ticket.is_refunded {
+                continue;
+            }
+            
+            // Kullanılmış ticket'ın geliri organizatöre, ücreti hazineye ayrılır
+            if ticket.is_used {
+                let revenue = ticket.price_paid - ticket.fee_paid;
+                event.escrow_balance -= revenue;
+                event.used_revenue += revenue;
+                event.fees_pending -= ticket.fee_paid;
+                Self::credit_treasury(env, ticket.fee_paid)?;
+                continue;
+            }
+            
+            // Transferi başarısız olan ticket'lar `claim_refund` ile talep edilir
+            if let Some(refunded_ticket) = Self::pay_refund(env, &mut event, ticket)? {
+                Self::save_ticket(env, &refunded_ticket, &event);
+            }
+        }
+        
+        event.refund_cursor = end;
+        Self::save_event(env, &event);
+        
+        Ok(total - end)
+    }
+
+    /// Ticket ücretini escrow'dan sahibine öder ve ticket'ı refund edildi olarak işaretler
+    ///
+    /// Platform ücreti de event'te bekletildiği için ödenen tutarın tamamı iade edilir.
+    /// Transfer başarısız olursa (ör. yetkisi kaldırılmış hesap) hiçbir şey değişmez
+    /// ve `None` döner; böylece tek bir alıcı batch'in tamamını durduramaz.
+    fn pay_refund(env: &Env, event: &mut Event, ticket: Ticket) -> Result<Option<Ticket>, Error> {
+        let amount = ticket.price_paid;
+        if amount > 0 {
+            let result = Self::token_client(env)?.try_transfer(
+                &env.current_contract_address(),
+                &ticket.owner,
+                &amount,
+            );
+            if !matches!(result, Ok(Ok(()))) {
+                return Ok(None);
+            }
+            event.escrow_balance -= amount - ticket.fee_paid;
+            event.platform_fees -= ticket.fee_paid;
+            event.fees_pending -= ticket.fee_paid;
+        }
+        
+        env.events().publish(
+            (symbol_short!("ticket"), symbol_short!("refunded"), ticket.id),
+            TicketRefundedPayload {
+                version: EVENTS_VERSION,
+                event_id: event.id,
+                owner: ticket.owner.clone(),
+                amount,
+            },
+        );
+        
+        Ok(Some(Ticket {
+            is_refunded: true,
+            ..ticket
+        }))
+    }
+
+    /// Event'in verilen seri numaralı ticket'ının ID'sini döndürür
+    fn event_ticket_id(env: &Env, event_id: u64, serial: u32) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EventTicket(event_id, serial))
+    }
+
+    /// İptal edilmiş event'in ticket sahibinin refund'unu kendisinin almasını sağlar
+    ///
+    /// Ödenen tutar escrow'dan ticket sahibine aktarılır ve döndürülür.
+    pub fn claim_refund(env: &Env, ticket_id: u64) -> Result<i128, Error> {
+        Self::require_initialized(env)?;
+        Self::extend_instance_ttl(env);
+        
+        // Ticket'ı kontrol et
+        let ticket = Self::load_ticket(env, ticket_id).ok_or(Error::TicketNotFound)?;
         
-        // Bu event'e ait tüm ticket'ları refund et
-        let _tickets: Map<Symbol, Ticket> = env.storage().instance().get(&TICKETS_KEY).unwrap();
+        // Sahiplik kontrolü
+        ticket.owner.require_auth();
+        
+        // Refund edilmiş mi kontrol et
+        if ticket.is_refunded {
+            return Err(Error::AlreadyRefunded);
+        }
+        
+        // Kullanılmış mı kontrol et
+        if ticket.is_used {
+            return Err(Error::AlreadyUsed);
+        }
         
-        // Not: Gerçek uygulamada tüm ticket'ları iterate etmek gerekir
-        // Bu basit implementasyonda sadece event'i iptal ediyoruz
-        // Refund işlemi frontend'de event cancellation event'ini dinleyerek yapılacak
+        // Event iptal edilmiş mi kontrol et
+        let mut event = Self::load_event(env, ticket.event_id).ok_or(Error::EventNotFound)?;
+        if !event.is_cancelled {
+            return Err(Error::EventNotCancelled);
+        }
+        
+        // Ücreti iade et
+        let amount = ticket.price_paid;
+        let refunded_ticket =
+            Self::pay_refund(env, &mut event, ticket)?.ok_or(Error::RefundFailed)?;
+        
+        Self::save_ticket(env, &refunded_ticket, &event);
+        Self::save_event(env, &event);
         
-        true
+        Ok(amount)
     }
 
     /// Ticket bilgilerini döndürür
-    pub fn get_ticket(env: &Env, ticket_id: Symbol) -> Option<Ticket> {
-        let tickets: Map<Symbol, Ticket> = env.storage().instance().get(&TICKETS_KEY).unwrap();
-        tickets.get(ticket_id)
+    pub fn get_ticket(env: &Env, ticket_id: u64) -> Result<Ticket, Error> {
+        Self::require_initialized(env)?;
+        Self::load_ticket(env, ticket_id).ok_or(Error::TicketNotFound)
+    }
+
+    /// Verilen ID'lere ait ticket'ları döndürür, bulunamayanlar atlanır
+    pub fn get_tickets(env: &Env, ticket_ids: Vec<u64>) -> Result<Vec<Ticket>, Error> {
+        Self::require_initialized(env)?;
+        
+        let mut result = Vec::new(env);
+        
+        for ticket_id in ticket_ids.iter() {
+            if let Some(ticket) = Self::load_ticket(env, ticket_id) {
+                result.push_back(ticket);
+            }
+        }
+        
+        Ok(result)
     }
 
     /// Event bilgilerini döndürür
-    pub fn get_event(env: &Env, event_id: Symbol) -> Option<Event> {
-        let events: Map<Symbol, Event> = env.storage().instance().get(&EVENTS_KEY).unwrap();
-        events.get(event_id)
+    pub fn get_event(env: &Env, event_id: u64) -> Result<Event, Error> {
+        Self::require_initialized(env)?;
+        Self::load_event(env, event_id).ok_or(Error::EventNotFound)
     }
 
     /// Tüm event'leri döndürür
-    pub fn get_all_events(env: &Env) -> Vec<Event> {
-        let events: Map<Symbol, Event> = env.storage().instance().get(&EVENTS_KEY).unwrap();
-        let event_ids: Vec<Symbol> = env.storage().instance().get(&EVENT_IDS_KEY).unwrap();
+    ///
+    /// Her event'i tek çağrıda okur; büyük kataloglarda `list_events` kullanılmalıdır.
+    pub fn get_all_events(env: &Env) -> Result<Vec<Event>, Error> {
+        Self::require_initialized(env)?;
+        
+        let event_count = Self::event_count(env)?;
         let mut all_events = Vec::new(env);
         
-        for event_id in event_ids.iter() {
-            if let Some(event) = events.get(event_id) {
+        for event_id in 1..=event_count {
+            if let Some(event) = Self::load_event(env, event_id) {
                 all_events.push_back(event);
             }
         }
         
-        all_events
+        Ok(all_events)
     }
 
-    /// Kullanıcının sahip olduğu tüm ticket'ları döndürür
-    pub fn get_user_tickets(env: &Env, _user: Address) -> Vec<Symbol> {
-        let _tickets: Map<Symbol, Ticket> = env.storage().instance().get(&TICKETS_KEY).unwrap();
-        let user_tickets = vec![env];
+    /// Event'leri ID sırasıyla sayfa sayfa döndürür
+    ///
+    /// `cursor` ID'sinden sonraki en fazla `limit` event taranır ve `filter`'a
+    /// uyanlar döndürülür; bu yüzden bir sayfa `limit`'ten az event içerebilir.
+    /// İlk sayfa için `cursor` 0 verilir.
+    pub fn list_events(
+        env: &Env,
+        cursor: u64,
+        limit: u32,
+        filter: EventFilter,
+    ) -> Result<EventPage, Error> {
+        Self::require_initialized(env)?;
         
-        // Not: Gerçek uygulamada tüm ticket'ları iterate etmek gerekir
-        // Bu basit implementasyonda boş liste döndürüyoruz
-        // Frontend'de event bazlı sorgulama yapılacak
+        let total = Self::event_count(env)?;
+        let limit = if limit == 0 || limit > PAGE_SIZE {
+            PAGE_SIZE
+        } else {
+            limit
+        };
+        let end = if total.saturating_sub(cursor) > limit as u64 {
+            cursor + limit as u64
+        } else {
+            total
+        };
+        
+        let mut events = Vec::new(env);
+        for event_id in (cursor + 1)..=end {
+            if let Some(event) = Self::load_event(env, event_id) {
+                if Self::event_matches(env, &event, &filter) {
+                    events.push_back(event);
+                }
+            }
+        }
         
-        user_tickets
+        Ok(EventPage {
+            events,
+            next_cursor: if end < total { Some(end) } else { None },
+        })
     }
 
-    /// Event için satılan ticket sayısını döndürür
-    pub fn get_event_ticket_count(env: &Env, event_id: Symbol) -> u32 {
-        let events: Map<Symbol, Event> = env.storage().instance().get(&EVENTS_KEY).unwrap();
-        let event = events.get(event_id).unwrap();
-        event.tickets_sold
+    /// Event'in durumunu döndürür
+    fn event_status(env: &Env, event: &Event) -> EventStatus {
+        if event.is_cancelled {
+            EventStatus::Cancelled
+        } else if event.is_paused {
+            EventStatus::Paused
+        } else if event.event_date < env.ledger().timestamp() {
+            EventStatus::Completed
+        } else {
+            EventStatus::Active
+        }
     }
 
-    /// Ticket'ın geçerli olup olmadığını kontrol eder
-    pub fn is_ticket_valid(env: &Env, ticket_id: Symbol) -> bool {
-        let tickets: Map<Symbol, Ticket> = env.storage().instance().get(&TICKETS_KEY).unwrap();
-        let ticket = tickets.get(ticket_id).unwrap();
+    /// Event'in listeleme filtresine uyup uymadığını kontrol eder
+    fn event_matches(env: &Env, event: &Event, filter: &EventFilter) -> bool {
+        if let Some(organizer) = &filter.organizer {
+            if &event.organizer != organizer {
+                return false;
+            }
+        }
         
-        // Event'i kontrol et
-        let events: Map<Symbol, Event> = env.storage().instance().get(&EVENTS_KEY).unwrap();
-        let event = events.get(ticket.event_id.clone()).unwrap();
+        if let Some(date_after) = filter.date_after {
+            if event.event_date < date_after {
+                return false;
+            }
+        }
         
-        // Ticket geçerli mi kontrol et
-        !ticket.is_used && !ticket.is_refunded && !event.is_cancelled && event.is_active
+        if let Some(date_before) = filter.date_before {
+            if event.event_date >= date_before {
+                return false;
+            }
+        }
+        
+        filter.statuses.is_empty() || filter.statuses.contains(Self::event_status(env, event))
     }
 
-    /// Admin adresini döndürür
-    pub fn get_admin(env: &Env) -> Address {
-        env.storage().instance().get(&ADMIN_KEY).unwrap()
+    /// Kullanıcının sahip olduğu ticket'ları sayfa sayfa döndürür
+    ///
+    /// Kullanıcının ticket indeksinde `cursor` sıra numarasından sonraki en fazla
+    /// `limit` kayıt taranır ve `filter`'a uyanlar döndürülür; bu yüzden bir sayfa
+    /// `limit`'ten az ticket içerebilir. Arşivlenecek event'lerin ticket'ları
+    /// atlanır. İlk sayfa için `cursor` 0 verilir.
+    pub fn get_user_tickets(
+        env: &Env,
+        user: Address,
+        cursor: u32,
+        limit: u32,
+        filter: TicketFilter,
+    ) -> Result<TicketPage, Error> {
+        Self::require_initialized(env)?;
+        
+        Self::extend_owner_index_ttl(env, &user);
+        let total = Self::owner_ticket_slots(env, &user);
+        let limit = if limit == 0 || limit > PAGE_SIZE {
+            PAGE_SIZE
+        } else {
+            limit
+        };
+        let end = if total.saturating_sub(cursor) > limit {
+            cursor + limit
+        } else {
+            total
+        };
+        
+        let config = Self::ttl_config(env);
+        let mut tickets = Vec::new(env);
+        for slot in (cursor + 1)..=end {
+            let ticket = match env
+                .storage()
+                .persistent()
+                .get(&DataKey::OwnerTicket(user.clone(), slot))
+                .and_then(|ticket_id| Self::load_ticket(env, ticket_id))
+            {
+                Some(ticket) => ticket,
+                None => continue,
+            };
+            
+            match Self::load_event(env, ticket.event_id) {
+                Some(event) if !Self::is_archivable(env, &event, &config) => {}
+                _ => continue,
+            }
+            Self::extend_owner_ticket_ttl(env, &ticket);
+            
+            if Self::ticket_matches(&ticket, &filter) {
+                tickets.push_back(ticket);
+            }
+        }
+        
+        Ok(TicketPage {
+            tickets,
+            next_cursor: if end < total { Some(end) } else { None },
+        })
     }
 
-    /// Admin'i değiştirir
-    pub fn set_admin(env: &Env, caller: Address, new_admin: Address) -> bool {
-        // Mevcut admin kontrolü
-        Self::require_admin(env, &caller);
+    /// Kullanıcının sahip olduğu ticket sayısını döndürür
+    pub fn get_user_ticket_count(env: &Env, user: Address) -> Result<u32, Error> {
+        Self::require_initialized(env)?;
+        Self::extend_owner_index_ttl(env, &user);
+        Ok(Self::owner_ticket_count(env, &user))
+    }
+
+    /// Ticket'ın durumunu döndürür
+    fn ticket_status(ticket: &Ticket) -> TicketStatus {
+        if ticket.is_refunded {
+            TicketStatus::Refunded
+        } else if ticket.is_used {
+            TicketStatus::Used
+        } else {
+            TicketStatus::Active
+        }
+    }
+
+    /// Ticket'ın listeleme filtresine uyup uymadığını kontrol eder
+    fn ticket_matches(ticket: &Ticket, filter: &TicketFilter) -> bool {
+        if let Some(event_id) = filter.event_id {
+            if ticket.event_id != event_id {
+                return false;
+            }
+        }
         
-        // Yeni admin'i kaydet
-        env.storage().instance().set(&ADMIN_KEY, &new_admin);
+        filter.statuses.is_empty() || filter.statuses.contains(Self::ticket_status(ticket))
+    }
+
+    /// Adresin sahip olduğu ticket sayısını okur
+    fn owner_ticket_count(env: &Env, owner: &Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::OwnerTicketCount(owner.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Adresin ticket indeksinde kullanılan son sıra numarasını okur
+    fn owner_ticket_slots(env: &Env, owner: &Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::OwnerTicketSlots(owner.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Ticket'ı sahibin indeksinin sonuna ekler ve sıra numarasını döndürür
+    fn add_owner_ticket(env: &Env, owner: &Address, ticket_id: u64) -> u32 {
+        let slot = Self::owner_ticket_slots(env, owner) + 1;
+        let count = Self::owner_ticket_count(env, owner) + 1;
+        
+        let storage = env.storage().persistent();
+        storage.set(&DataKey::OwnerTicket(owner.clone(), slot), &ticket_id);
+        storage.set(&DataKey::OwnerTicketSlots(owner.clone()), &slot);
+        storage.set(&DataKey::OwnerTicketCount(owner.clone()), &count);
+        Self::extend_owner_index_ttl(env, owner);
         
-        true
+        slot
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use soroban_sdk::{
-        symbol_short, Env,
-    };
-    use soroban_sdk::testutils::Address as TestAddress;
+    /// Sahibin indeksinden verilen sıradaki ticket'ı çıkarır
+    ///
+    /// Diğer kayıtlar taşınmaz; arşivlenmiş event'lerin ticket'larına dokunmamak
+    /// için çıkarılan sıra boş bırakılır ve sayfalı sorgularda atlanır.
+    fn remove_owner_ticket(env: &Env, owner: &Address, slot: u32) {
+        let count = Self::owner_ticket_count(env, owner);
+        
+        let storage = env.storage().persistent();
+        storage.remove(&DataKey::OwnerTicket(owner.clone(), slot));
+        storage.set(&DataKey::OwnerTicketCount(owner.clone()), &count.saturating_sub(1));
+        Self::extend_owner_index_ttl(env, owner);
+    }
+
+    /// Event'in ticket'larını seri numarası sırasıyla sayfa sayfa döndürür
+    ///
+    /// `cursor` seri numarasından sonraki en fazla `limit` ticket döndürülür.
+    /// İlk sayfa için `cursor` 0 verilir.
+    pub fn get_event_tickets(
+        env: &Env,
+        event_id: u64,
+        cursor: u32,
+        limit: u32,
+    ) -> Result<EventTicketPage, Error> {
+        Self::require_initialized(env)?;
+        
+        if Self::load_event(env, event_id).is_none() {
+            return Err(Error::EventNotFound);
+        }
+        
+        let total = Self::last_serial(env, event_id);
+        let limit = if limit == 0 || limit > PAGE_SIZE {
+            PAGE_SIZE
+        } else {
+            limit
+        };
+        let end = if total.saturating_sub(cursor) > limit {
+            cursor + limit
+        } else {
+            total
+        };
+        
+        let mut tickets = Vec::new(env);
+        for serial in (cursor + 1)..=end {
+            if let Some(ticket) = Self::event_ticket_id(env, event_id, serial)
+                .and_then(|ticket_id| Self::load_ticket(env, ticket_id))
+            {
+                tickets.push_back(TicketSummary {
+                    id: ticket.id,
+                    serial: ticket.serial,
+                    status: Self::ticket_status(&ticket),
+                    owner: ticket.owner,
+                });
+            }
+        }
+        
+        Ok(EventTicketPage {
+            tickets,
+            next_cursor: if end < total { Some(end) } else { None },
+        })
+    }
+
+    /// Event için satılan ticket sayısını döndürür
+    pub fn get_event_ticket_count(env: &Env, event_id: u64) -> Result<u32, Error> {
+        Self::require_initialized(env)?;
+        
+        let event = Self::load_event(env, event_id).ok_or(Error::EventNotFound)?;
+        Ok(event.tickets_sold)
+    }
+
+    /// Şimdiye kadar oluşturulan event sayısını döndürür
+    pub fn event_count(env: &Env) -> Result<u64, Error> {
+        Self::require_initialized(env)?;
+        Ok(env.storage().instance().get(&EVENT_COUNTER_KEY).unwrap_or(0))
+    }
+
+    /// Şimdiye kadar mint edilen ticket sayısını döndürür
+    pub fn ticket_count(env: &Env) -> Result<u64, Error> {
+        Self::require_initialized(env)?;
+        Ok(env.storage().instance().get(&TICKET_COUNTER_KEY).unwrap_or(0))
+    }
+
+    /// Event için verilen son ticket seri numarasını döndürür
+    pub fn event_ticket_serial(env: &Env, event_id: u64) -> Result<u32, Error> {
+        Self::require_initialized(env)?;
+        Ok(Self::last_serial(env, event_id))
+    }
+
+    /// Event'in verdiği son ticket seri numarasını storage'dan okur
+    fn last_serial(env: &Env, event_id: u64) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EventSerial(event_id))
+            .unwrap_or(0)
+    }
+
+    /// Ticket'ın geçerli olup olmadığını kontrol eder
+    pub fn is_ticket_valid(env: &Env, ticket_id: u64) -> Result<bool, Error> {
+        Self::require_initialized(env)?;
+        
+        let ticket = Self::load_ticket(env, ticket_id).ok_or(Error::TicketNotFound)?;
+        
+        // Event'i kontrol et
+        let event = Self::load_event(env, ticket.event_id).ok_or(Error::EventNotFound)?;
+        
+        // Ticket geçerli mi kontrol et
+        Ok(!ticket.is_used
+            && !ticket.is_refunded
+            && !event.is_cancelled
+            && event.is_active
+            && !event.is_paused)
+    }
+
+    /// Event için escrow'da tutulan bakiyeyi döndürür
+    pub fn get_event_escrow(env: &Env, event_id: u64) -> Result<i128, Error> {
+        Self::require_initialized(env)?;
+        
+        let event = Self::load_event(env, event_id).ok_or(Error::EventNotFound)?;
+        Ok(event.escrow_balance)
+    }
+
+    /// Event'in escrow'daki gelirini organizatöre öder
+    ///
+    /// Gelir ancak `event_date` ve bekleme süresi geçtikten sonra çekilebilir.
+    /// İptal edilmiş event'lerde yalnızca refund sırasında kullanılmış ticket'lar için
+    /// ayrılan gelir ödenir. Event'te bekleyen platform ücreti aynı anda hazineye
+    /// aktarılır. Organizatöre ödenen tutarı döndürür.
+    pub fn withdraw_revenue(env: &Env, event_id: u64) -> Result<i128, Error> {
+        Self::require_initialized(env)?;
+        Self::require_not_paused(env)?;
+        Self::extend_instance_ttl(env);
+        
+        let mut event = Self::load_event(env, event_id).ok_or(Error::EventNotFound)?;
+        
+        // Organizatör imza kontrolü
+        event.organizer.require_auth();
+        
+        // İptal edilmiş event'in escrow'u refund'lara aittir
+        if event.is_cancelled && event.used_revenue <= 0 {
+            return Err(Error::EventCancelled);
+        }
+        
+        Self::require_unlocked(env, &event)?;
+        
+        let (amount, fees) = if event.is_cancelled {
+            (event.used_revenue, 0)
+        } else {
+            (event.escrow_balance, event.fees_pending)
+        };
+        if amount <= 0 && fees <= 0 {
+            return Err(Error::NothingToWithdraw);
+        }
+        
+        if amount > 0 {
+            Self::token_client(env)?.transfer(
+                &env.current_contract_address(),
+                &event.organizer,
+                &amount,
+            );
+        }
+        if event.is_cancelled {
+            event.used_revenue = 0;
+        } else {
+            event.escrow_balance = 0;
+        }
+        event.revenue_withdrawn += amount;
+        
+        // Bekleyen platform ücretini hazineye aktar
+        if fees > 0 {
+            Self::credit_treasury(env, fees)?;
+            event.fees_pending = 0;
+        }
+        Self::save_event(env, &event);
+        
+        env.events().publish(
+            (symbol_short!("event"), symbol_short!("payout"), event_id),
+            RevenueWithdrawnPayload {
+                version: EVENTS_VERSION,
+                organizer: event.organizer,
+                amount,
+                total_withdrawn: event.revenue_withdrawn,
+            },
+        );
+        
+        Ok(amount)
+    }
+
+    /// Event tarihi ve bekleme süresi geçmediyse `RevenueLocked` hatası verir
+    fn require_unlocked(env: &Env, event: &Event) -> Result<(), Error> {
+        let unlock_at = event
+            .event_date
+            .saturating_add(Self::hold_period(env));
+        if env.ledger().timestamp() <= unlock_at {
+            return Err(Error::RevenueLocked);
+        }
+        Ok(())
+    }
+
+    /// Gelirin event tarihinden sonra bekletildiği süreyi (saniye) döndürür
+    pub fn get_hold_period(env: &Env) -> Result<u64, Error> {
+        Self::require_initialized(env)?;
+        Ok(Self::hold_period(env))
+    }
+
+    /// Gelir bekleme süresini günceller (sadece admin)
+    ///
+    /// Konsey kuruluysa `CouncilAction::SetHoldPeriod` önerisiyle yapılmalıdır.
+    pub fn set_hold_period(env: &Env, caller: Address, seconds: u64) -> Result<bool, Error> {
+        Self::require_initialized(env)?;
+        Self::require_admin(env, &caller)?;
+        Self::require_no_council(env)?;
+        Self::extend_instance_ttl(env);
+        
+        env.storage().instance().set(&HOLD_PERIOD_KEY, &seconds);
+        
+        Ok(true)
+    }
+
+    /// Kayıtlı bekleme süresini, yoksa varsayılanı okur
+    fn hold_period(env: &Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&HOLD_PERIOD_KEY)
+            .unwrap_or(REVENUE_HOLD_PERIOD)
+    }
+
+    /// Varsayılan platform ücretini döndürür
+    pub fn get_platform_fee(env: &Env) -> Result<FeeConfig, Error> {
+        Self::require_initialized(env)?;
+        Ok(Self::default_fee(env))
+    }
+
+    /// Varsayılan platform ücretini günceller (sadece admin)
+    ///
+    /// Konsey kuruluysa `CouncilAction::SetPlatformFee` önerisiyle yapılmalıdır.
+    pub fn set_platform_fee(env: &Env, caller: Address, fee: FeeConfig) -> Result<bool, Error> {
+        Self::require_initialized(env)?;
+        Self::require_admin(env, &caller)?;
+        Self::require_no_council(env)?;
+        Self::extend_instance_ttl(env);
+        
+        Self::store_platform_fee(env, fee)?;
+        
+        Ok(true)
+    }
+
+    /// Varsayılan platform ücretini doğrulayıp kaydeder
+    fn store_platform_fee(env: &Env, fee: FeeConfig) -> Result<(), Error> {
+        Self::require_valid_fee(&fee)?;
+        env.storage().instance().set(&FEE_CONFIG_KEY, &fee);
+        
+        env.events().publish(
+            (symbol_short!("fee"), symbol_short!("changed")),
+            FeeChangedPayload {
+                version: EVENTS_VERSION,
+                organizer: None,
+                fee,
+            },
+        );
+        
+        Ok(())
+    }
+
+    /// Organizatöre özel platform ücretini döndürür
+    pub fn get_organizer_fee(env: &Env, organizer: Address) -> Result<Option<FeeConfig>, Error> {
+        Self::require_initialized(env)?;
+        Ok(Self::organizer_fee(env, &organizer))
+    }
+
+    /// Organizatöre özel platform ücretini ayarlar; `None` varsayılan ücrete döndürür (sadece admin)
+    ///
+    /// Konsey kuruluysa `CouncilAction::SetOrganizerFee` veya
+    /// `CouncilAction::ClearOrganizerFee` önerisiyle yapılmalıdır.
+    pub fn set_organizer_fee(
+        env: &Env,
+        caller: Address,
+        organizer: Address,
+        fee: Option<FeeConfig>,
+    ) -> Result<bool, Error> {
+        Self::require_initialized(env)?;
+        Self::require_admin(env, &caller)?;
+        Self::require_no_council(env)?;
+        Self::extend_instance_ttl(env);
+        
+        Self::store_organizer_fee(env, organizer, fee)?;
+        
+        Ok(true)
+    }
+
+    /// Organizatöre özel ücreti doğrulayıp kaydeder ya da kaldırır
+    fn store_organizer_fee(
+        env: &Env,
+        organizer: Address,
+        fee: Option<FeeConfig>,
+    ) -> Result<(), Error> {
+        let key = DataKey::OrganizerFee(organizer.clone());
+        match fee {
+            Some(fee) => {
+                Self::require_valid_fee(&fee)?;
+                env.storage().persistent().set(&key, &fee);
+                Self::extend_organizer_fee_ttl(env, &organizer);
+                
+                env.events().publish(
+                    (symbol_short!("fee"), symbol_short!("changed")),
+                    FeeChangedPayload {
+                        version: EVENTS_VERSION,
+                        organizer: Some(organizer),
+                        fee,
+                    },
+                );
+            }
+            None => {
+                env.storage().persistent().remove(&key);
+                
+                env.events().publish(
+                    (symbol_short!("fee"), symbol_short!("cleared")),
+                    FeeClearedPayload {
+                        version: EVENTS_VERSION,
+                        organizer,
+                    },
+                );
+            }
+        }
+        
+        Ok(())
+    }
+
+    /// Event satışlarından kesilen toplam platform ücretini döndürür
+    pub fn get_event_fees(env: &Env, event_id: u64) -> Result<i128, Error> {
+        Self::require_initialized(env)?;
+        
+        let event = Self::load_event(env, event_id).ok_or(Error::EventNotFound)?;
+        Ok(event.platform_fees)
+    }
+
+    /// Hazinede çekilmeyi bekleyen platform ücretlerini döndürür
+    ///
+    /// Event ücretleri hazineye `withdraw_revenue` veya `settle_fees` ile aktarılır.
+    pub fn get_platform_balance(env: &Env) -> Result<i128, Error> {
+        Self::require_initialized(env)?;
+        Ok(Self::platform_balance(env))
+    }
+
+    /// Event'te bekleyen platform ücretini organizatöre ödeme yapmadan hazineye aktarır
+    ///
+    /// Herkes çağırabilir; `event_date` ve bekleme süresi geçmiş olmalıdır.
+    /// Aktarılan tutarı döndürür.
+    pub fn settle_fees(env: &Env, event_id: u64) -> Result<i128, Error> {
+        Self::require_initialized(env)?;
+        Self::require_not_paused(env)?;
+        Self::extend_instance_ttl(env);
+        
+        let mut event = Self::load_event(env, event_id).ok_or(Error::EventNotFound)?;
+        
+        // İptal edilmiş event'in bekleyen ücretleri refund'lara aittir
+        if event.is_cancelled {
+            return Err(Error::EventCancelled);
+        }
+        
+        Self::require_unlocked(env, &event)?;
+        
+        let amount = event.fees_pending;
+        if amount <= 0 {
+            return Err(Error::NothingToWithdraw);
+        }
+        
+        Self::credit_treasury(env, amount)?;
+        event.fees_pending = 0;
+        Self::save_event(env, &event);
+        
+        env.events().publish(
+            (symbol_short!("fee"), symbol_short!("settled"), event_id),
+            FeesSettledPayload {
+                version: EVENTS_VERSION,
+                amount,
+            },
+        );
+        
+        Ok(amount)
+    }
+
+    /// Hazinedeki platform ücretlerini `to` adresine öder (sadece admin)
+    ///
+    /// Konsey kuruluysa `CouncilAction::WithdrawPlatformFees` önerisiyle yapılmalıdır.
+    /// Ödenen tutarı döndürür.
+    pub fn withdraw_platform_fees(env: &Env, caller: Address, to: Address) -> Result<i128, Error> {
+        Self::require_initialized(env)?;
+        Self::require_admin(env, &caller)?;
+        Self::require_no_council(env)?;
+        Self::require_not_paused(env)?;
+        Self::extend_instance_ttl(env);
+        
+        Self::pay_platform_fees(env, to)
+    }
+
+    /// Hazinedeki tüm bakiyeyi `to` adresine aktarır
+    fn pay_platform_fees(env: &Env, to: Address) -> Result<i128, Error> {
+        let amount = Self::platform_balance(env);
+        if amount <= 0 {
+            return Err(Error::NothingToWithdraw);
+        }
+        
+        Self::token_client(env)?.transfer(&env.current_contract_address(), &to, &amount);
+        env.storage().instance().set(&TREASURY_KEY, &0i128);
+        
+        env.events().publish(
+            (symbol_short!("fee"), symbol_short!("withdrawn")),
+            FeesWithdrawnPayload {
+                version: EVENTS_VERSION,
+                to,
+                amount,
+            },
+        );
+        
+        Ok(amount)
+    }
+
+    /// Organizatörün bilet fiyatı için ödeyeceği platform ücretini hesaplar
+    ///
+    /// Oran hesabı taşacak kadar yüksek fiyatlar `InvalidPrice` ile reddedilir.
+    fn ticket_fee(env: &Env, organizer: &Address, price: i128) -> Result<i128, Error> {
+        if price <= 0 {
+            return Ok(0);
+        }
+        let fee = Self::organizer_fee(env, organizer).unwrap_or_else(|| Self::default_fee(env));
+        let amount = price
+            .checked_mul(fee.bps as i128)
+            .ok_or(Error::InvalidPrice)?
+            / 10_000;
+        Ok(amount.saturating_add(fee.flat).min(price))
+    }
+
+    /// Ücret oranının %100'ü aşmadığını ve sabit ücretin negatif olmadığını doğrular
+    fn require_valid_fee(fee: &FeeConfig) -> Result<(), Error> {
+        if fee.bps > 10_000 || fee.flat < 0 {
+            return Err(Error::InvalidFee);
+        }
+        Ok(())
+    }
+
+    /// Kayıtlı varsayılan ücreti, yoksa ücretsiz yapılandırmayı okur
+    fn default_fee(env: &Env) -> FeeConfig {
+        env.storage()
+            .instance()
+            .get(&FEE_CONFIG_KEY)
+            .unwrap_or(FeeConfig { bps: 0, flat: 0 })
+    }
+
+    /// Organizatöre özel ücreti okur ve varsa TTL'ini uzatır
+    fn organizer_fee(env: &Env, organizer: &Address) -> Option<FeeConfig> {
+        let fee = env
+            .storage()
+            .persistent()
+            .get(&DataKey::OrganizerFee(organizer.clone()));
+        if fee.is_some() {
+            Self::extend_organizer_fee_ttl(env, organizer);
+        }
+        fee
+    }
+
+    /// Hazine bakiyesine `amount` ekler
+    fn credit_treasury(env: &Env, amount: i128) -> Result<(), Error> {
+        if amount <= 0 {
+            return Ok(());
+        }
+        let treasury = Self::platform_balance(env)
+            .checked_add(amount)
+            .ok_or(Error::InvalidPrice)?;
+        env.storage().instance().set(&TREASURY_KEY, &treasury);
+        Ok(())
+    }
+
+    /// Hazinedeki platform ücreti bakiyesini okur
+    fn platform_balance(env: &Env) -> i128 {
+        env.storage().instance().get(&TREASURY_KEY).unwrap_or(0)
+    }
+
+    /// Ödeme token'ının adresini döndürür
+    pub fn get_token(env: &Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&TOKEN_KEY)
+            .ok_or(Error::NotInitialized)
+    }
+
+    /// Event kaydını persistent storage'dan okur
+    fn load_event(env: &Env, event_id: u64) -> Option<Event> {
+        env.storage().persistent().get(&DataKey::Event(event_id))
+    }
+
+    /// Event kaydını persistent storage'a yazar ve TTL'ini uzatır
+    fn save_event(env: &Env, event: &Event) {
+        env.storage().persistent().set(&DataKey::Event(event.id), event);
+        Self::extend_event_ttl(env, event);
+    }
+
+    /// Bilet kategorisini persistent storage'dan okur
+    fn load_tier(env: &Env, event_id: u64, tier_id: u32) -> Option<TicketTier> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EventTier(event_id, tier_id))
+    }
+
+    /// Bilet kategorisini persistent storage'a yazar ve event politikasına göre TTL'ini uzatır
+    fn save_tier(env: &Env, event: &Event, tier: &TicketTier) {
+        let key = DataKey::EventTier(event.id, tier.id);
+        env.storage().persistent().set(&key, tier);
+        
+        let config = Self::ttl_config(env);
+        if !Self::is_archivable(env, event, &config) {
+            env.storage().persistent().extend_ttl(
+                &key,
+                config.persistent_threshold,
+                config.persistent_extend_to,
+            );
+        }
+    }
+
+    /// Koltuk bölümünü persistent storage'dan okur
+    fn load_section(env: &Env, event_id: u64, section_id: u32) -> Option<SeatSection> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EventSection(event_id, section_id))
+    }
+
+    /// Koltuk bölümünü persistent storage'a yazar ve event politikasına göre TTL'ini uzatır
+    fn save_section(env: &Env, event: &Event, section: &SeatSection) {
+        let key = DataKey::EventSection(event.id, section.id);
+        env.storage().persistent().set(&key, section);
+        
+        let config = Self::ttl_config(env);
+        if !Self::is_archivable(env, event, &config) {
+            env.storage().persistent().extend_ttl(
+                &key,
+                config.persistent_threshold,
+                config.persistent_extend_to,
+            );
+        }
+    }
+
+    /// Ticket kaydını persistent storage'dan okur
+    fn load_ticket(env: &Env, ticket_id: u64) -> Option<Ticket> {
+        env.storage().persistent().get(&DataKey::Ticket(ticket_id))
+    }
+
+    /// Ticket kaydını persistent storage'a yazar ve event politikasına göre TTL'ini uzatır
+    fn save_ticket(env: &Env, ticket: &Ticket, event: &Event) {
+        env.storage().persistent().set(&DataKey::Ticket(ticket.id), ticket);
+        Self::extend_ticket_ttl(env, ticket, event);
+    }
+
+    /// Geçerli TTL ayarlarını döndürür
+    pub fn get_ttl_config(env: &Env) -> Result<TtlConfig, Error> {
+        Self::require_initialized(env)?;
+        Ok(Self::ttl_config(env))
+    }
+
+    /// Kayıtlı TTL ayarlarını, yoksa varsayılanları okur
+    fn ttl_config(env: &Env) -> TtlConfig {
+        env.storage()
+            .instance()
+            .get(&TTL_CONFIG_KEY)
+            .unwrap_or(TtlConfig {
+                instance_threshold: INSTANCE_THRESHOLD,
+                instance_extend_to: INSTANCE_EXTEND_TO,
+                persistent_threshold: PERSISTENT_THRESHOLD,
+                persistent_extend_to: PERSISTENT_EXTEND_TO,
+                archive_after: ARCHIVE_AFTER,
+            })
+    }
+
+    /// TTL ayarlarını günceller (sadece admin)
+    ///
+    /// Konsey kuruluysa `CouncilAction::SetTtlConfig` önerisiyle yapılmalıdır.
+    pub fn set_ttl_config(env: &Env, caller: Address, config: TtlConfig) -> Result<bool, Error> {
+        Self::require_initialized(env)?;
+        Self::require_admin(env, &caller)?;
+        Self::require_no_council(env)?;
+        
+        Self::store_ttl_config(env, config)?;
+        Self::extend_instance_ttl(env);
+        
+        Ok(true)
+    }
+
+    /// TTL ayarlarını doğrulayıp kaydeder
+    fn store_ttl_config(env: &Env, config: TtlConfig) -> Result<(), Error> {
+        if config.instance_threshold > config.instance_extend_to
+            || config.persistent_threshold > config.persistent_extend_to
+        {
+            return Err(Error::InvalidTtlConfig);
+        }
+        
+        env.storage().instance().set(&TTL_CONFIG_KEY, &config);
+        Ok(())
+    }
+
+    /// Event kaydını ve ticket'larını canlı tutmak için TTL'lerini uzatır
+    ///
+    /// Herkes çağırabilir. Ticket'lar `cursor` seri numarasından sonra en fazla
+    /// `limit` adet olacak şekilde işlenir; bir sonraki çağrı için cursor döndürülür.
+    /// Arşiv süresi dolmuş event'ler uzatılamaz.
+    pub fn bump_event(env: &Env, event_id: u64, cursor: u32, limit: u32) -> Result<u32, Error> {
+        Self::require_initialized(env)?;
+        Self::extend_instance_ttl(env);
+        
+        let event = Self::load_event(env, event_id).ok_or(Error::EventNotFound)?;
+        
+        let config = Self::ttl_config(env);
+        if Self::is_archivable(env, &event, &config) {
+            return Err(Error::EventArchived);
+        }
+        
+        Self::extend_event_ttl(env, &event);
+        
+        let total = Self::last_serial(env, event_id);
+        let limit = if limit == 0 || limit > BUMP_BATCH_SIZE {
+            BUMP_BATCH_SIZE
+        } else {
+            limit
+        };
+        let end = if total.saturating_sub(cursor) > limit {
+            cursor + limit
+        } else {
+            total
+        };
+        
+        for serial in (cursor + 1)..=end {
+            if let Some(ticket) = Self::event_ticket_id(env, event_id, serial)
+                .and_then(|ticket_id| Self::load_ticket(env, ticket_id))
+            {
+                Self::extend_ticket_ttl(env, &ticket, &event);
+            }
+        }
+        
+        Ok(end)
+    }
+
+    /// Contract instance'ının TTL'ini uzatır
+    fn extend_instance_ttl(env: &Env) {
+        let config = Self::ttl_config(env);
+        env.storage()
+            .instance()
+            .extend_ttl(config.instance_threshold, config.instance_extend_to);
+    }
+
+    /// Event kaydının ve seri sayacının TTL'ini uzatır; arşivlenecek event'ler atlanır
+    fn extend_event_ttl(env: &Env, event: &Event) {
+        let config = Self::ttl_config(env);
+        if Self::is_archivable(env, event, &config) {
+            return;
+        }
+        
+        let storage = env.storage().persistent();
+        storage.extend_ttl(
+            &DataKey::Event(event.id),
+            config.persistent_threshold,
+            config.persistent_extend_to,
+        );
+        
+        let serial_key = DataKey::EventSerial(event.id);
+        if storage.has(&serial_key) {
+            storage.extend_ttl(
+                &serial_key,
+                config.persistent_threshold,
+                config.persistent_extend_to,
+            );
+        }
+        
+        for tier_id in 1..=event.tier_count {
+            storage.extend_ttl(
+                &DataKey::EventTier(event.id, tier_id),
+                config.persistent_threshold,
+                config.persistent_extend_to,
+            );
+        }
+        
+        for section_id in 1..=event.section_count {
+            storage.extend_ttl(
+                &DataKey::EventSection(event.id, section_id),
+                config.persistent_threshold,
+                config.persistent_extend_to,
+            );
+        }
+    }
+
+    /// Ticket kaydının ve event ile sahip indekslerindeki girdilerinin TTL'ini uzatır
+    fn extend_ticket_ttl(env: &Env, ticket: &Ticket, event: &Event) {
+        let config = Self::ttl_config(env);
+        if Self::is_archivable(env, event, &config) {
+            return;
+        }
+        
+        Self::extend_owner_ticket_ttl(env, ticket);
+        
+        let storage = env.storage().persistent();
+        storage.extend_ttl(
+            &DataKey::Ticket(ticket.id),
+            config.persistent_threshold,
+            config.persistent_extend_to,
+        );
+        storage.extend_ttl(
+            &DataKey::EventTicket(ticket.event_id, ticket.serial),
+            config.persistent_threshold,
+            config.persistent_extend_to,
+        );
+        if let TicketSeat::Assigned(seat) = &ticket.seat {
+            let seat_key = Self::seat_key(ticket.event_id, seat);
+            if storage.has(&seat_key) {
+                storage.extend_ttl(
+                    &seat_key,
+                    config.persistent_threshold,
+                    config.persistent_extend_to,
+                );
+            }
+        }
+    }
+
+    /// Ticket'ın sahip indeksindeki girdisinin ve indeks sayaçlarının TTL'ini uzatır
+    fn extend_owner_ticket_ttl(env: &Env, ticket: &Ticket) {
+        let config = Self::ttl_config(env);
+        let key = DataKey::OwnerTicket(ticket.owner.clone(), ticket.owner_slot);
+        if env.storage().persistent().has(&key) {
+            env.storage().persistent().extend_ttl(
+                &key,
+                config.persistent_threshold,
+                config.persistent_extend_to,
+            );
+        }
+        Self::extend_owner_index_ttl(env, &ticket.owner);
+    }
+
+    /// Sahip indeksinin sayaçlarının TTL'ini uzatır
+    ///
+    /// Sayaçlar adresin tüm event'leri için ortaktır; bu yüzden event'lerin
+    /// arşivleme politikasından bağımsız olarak her okuma ve yazmada uzatılır.
+    fn extend_owner_index_ttl(env: &Env, owner: &Address) {
+        let config = Self::ttl_config(env);
+        let storage = env.storage().persistent();
+        for key in [
+            DataKey::OwnerTicketCount(owner.clone()),
+            DataKey::OwnerTicketSlots(owner.clone()),
+        ] {
+            if storage.has(&key) {
+                storage.extend_ttl(
+                    &key,
+                    config.persistent_threshold,
+                    config.persistent_extend_to,
+                );
+            }
+        }
+    }
+
+    /// Event'in arşiv süresinin dolup dolmadığını kontrol eder
+    ///
+    /// Escrow'da ödenmemiş bakiyesi, hazineye aktarılmamış platform ücreti,
+    /// çekilmemiş gelir veya bekleyen refund'ları olan event'ler arşivlenmez.
+    fn is_archivable(env: &Env, event: &Event, config: &TtlConfig) -> bool {
+        let archive_at = event.event_date.saturating_add(config.archive_after);
+        if env.ledger().timestamp() <= archive_at {
+            return false;
+        }
+        
+        if event.escrow_balance > 0 || event.fees_pending > 0 || event.used_revenue > 0 {
+            return false;
+        }
+        
+        !event.is_cancelled || event.refund_cursor >= Self::last_serial(env, event.id)
+    }
+
+    /// Ödeme token'ı için client oluşturur
+    fn token_client(env: &Env) -> Result<token::Client<'_>, Error> {
+        let token = Self::get_token(env)?;
+        Ok(token::Client::new(env, &token))
+    }
+
+    /// Admin adresini döndürür
+    pub fn get_admin(env: &Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&ADMIN_KEY)
+            .ok_or(Error::NotInitialized)
+    }
+
+    /// Admin devrini önerir; devir yeni admin `accept_admin` ile kabul edince tamamlanır
+    ///
+    /// `expires_at` verilirse öneri bu zamandan (ledger timestamp) sonra kabul edilemez.
+    /// Yeni öneri bekleyen öneriyi geçersiz kılar.
+    pub fn propose_admin(
+        env: &Env,
+        caller: Address,
+        new_admin: Address,
+        expires_at: Option<u64>,
+    ) -> Result<bool, Error> {
+        Self::require_initialized(env)?;
+        
+        // Mevcut admin kontrolü
+        Self::require_admin(env, &caller)?;
+        Self::require_no_council(env)?;
+        Self::extend_instance_ttl(env);
+        
+        let pending = PendingAdmin {
+            new_admin: new_admin.clone(),
+            expires_at,
+        };
+        env.storage().instance().set(&PENDING_ADMIN_KEY, &pending);
+        
+        env.events().publish(
+            (symbol_short!("admin"), symbol_short!("proposed")),
+            AdminProposalPayload {
+                version: EVENTS_VERSION,
+                admin: caller,
+                new_admin,
+                expires_at,
+            },
+        );
+        
+        Ok(true)
+    }
+
+    /// Bekleyen admin devrini kabul eder (sadece önerilen adres)
+    pub fn accept_admin(env: &Env, caller: Address) -> Result<bool, Error> {
+        Self::require_initialized(env)?;
+        Self::extend_instance_ttl(env);
+        
+        let pending: PendingAdmin = env
+            .storage()
+            .instance()
+            .get(&PENDING_ADMIN_KEY)
+            .ok_or(Error::NoPendingAdmin)?;
+        
+        // Önerilen adres kontrolü
+        if caller != pending.new_admin {
+            return Err(Error::Unauthorized);
+        }
+        caller.require_auth();
+        
+        if let Some(expires_at) = pending.expires_at {
+            if env.ledger().timestamp() > expires_at {
+                return Err(Error::ProposalExpired);
+            }
+        }
+        
+        Self::replace_admin(env, caller)?;
+        
+        Ok(true)
+    }
+
+    /// Admin adresini değiştirir ve bekleyen devir önerisini temizler
+    fn replace_admin(env: &Env, new_admin: Address) -> Result<(), Error> {
+        let previous_admin = Self::get_admin(env)?;
+        env.storage().instance().set(&ADMIN_KEY, &new_admin);
+        env.storage().instance().remove(&PENDING_ADMIN_KEY);
+        
+        env.events().publish(
+            (symbol_short!("admin"), symbol_short!("changed")),
+            AdminChangedPayload {
+                version: EVENTS_VERSION,
+                previous_admin: Some(previous_admin),
+                new_admin,
+            },
+        );
+        
+        Ok(())
+    }
+
+    /// Bekleyen admin devri önerisini iptal eder (sadece admin)
+    pub fn cancel_admin_proposal(env: &Env, caller: Address) -> Result<bool, Error> {
+        Self::require_initialized(env)?;
+        
+        // Mevcut admin kontrolü
+        Self::require_admin(env, &caller)?;
+        Self::extend_instance_ttl(env);
+        
+        let pending: PendingAdmin = env
+            .storage()
+            .instance()
+            .get(&PENDING_ADMIN_KEY)
+            .ok_or(Error::NoPendingAdmin)?;
+        env.storage().instance().remove(&PENDING_ADMIN_KEY);
+        
+        env.events().publish(
+            (symbol_short!("admin"), symbol_short!("cancelled")),
+            AdminProposalPayload {
+                version: EVENTS_VERSION,
+                admin: caller,
+                new_admin: pending.new_admin,
+                expires_at: pending.expires_at,
+            },
+        );
+        
+        Ok(true)
+    }
+
+    /// Bekleyen admin devri önerisini döndürür
+    pub fn get_pending_admin(env: &Env) -> Result<Option<PendingAdmin>, Error> {
+        Self::require_initialized(env)?;
+        Ok(env.storage().instance().get(&PENDING_ADMIN_KEY))
+    }
+
+    /// Contract'ı acil durum için durdurur (admin veya guardian)
+    ///
+    /// Durdurulmuş contract'ta satın alma, transfer ve check-in yapılamaz;
+    /// refund'lar ve sorgular çalışmaya devam eder.
+    pub fn pause(env: &Env, caller: Address) -> Result<bool, Error> {
+        Self::require_initialized(env)?;
+        Self::require_pauser(env, &caller)?;
+        Self::extend_instance_ttl(env);
+        
+        env.storage().instance().set(&PAUSED_KEY, &true);
+        
+        env.events().publish(
+            (symbol_short!("contract"), symbol_short!("paused")),
+            PausePayload {
+                version: EVENTS_VERSION,
+                by: caller,
+            },
+        );
+        
+        Ok(true)
+    }
+
+    /// Durdurulmuş contract'ı yeniden başlatır (sadece admin)
+    pub fn unpause(env: &Env, caller: Address) -> Result<bool, Error> {
+        Self::require_initialized(env)?;
+        Self::require_admin(env, &caller)?;
+        Self::extend_instance_ttl(env);
+        
+        env.storage().instance().remove(&PAUSED_KEY);
+        
+        env.events().publish(
+            (symbol_short!("contract"), symbol_short!("unpaused")),
+            PausePayload {
+                version: EVENTS_VERSION,
+                by: caller,
+            },
+        );
+        
+        Ok(true)
+    }
+
+    /// Contract'ın durdurulup durdurulmadığını döndürür
+    pub fn is_paused(env: &Env) -> Result<bool, Error> {
+        Self::require_initialized(env)?;
+        Ok(env.storage().instance().get(&PAUSED_KEY).unwrap_or(false))
+    }
+
+    /// Tek bir event'te satın alma, transfer ve check-in'i durdurur (admin veya guardian)
+    pub fn pause_event(env: &Env, caller: Address, event_id: u64) -> Result<bool, Error> {
+        Self::require_initialized(env)?;
+        Self::require_pauser(env, &caller)?;
+        Self::extend_instance_ttl(env);
+        
+        Self::set_event_paused(env, event_id, true)?;
+        
+        env.events().publish(
+            (symbol_short!("event"), symbol_short!("paused"), event_id),
+            PausePayload {
+                version: EVENTS_VERSION,
+                by: caller,
+            },
+        );
+        
+        Ok(true)
+    }
+
+    /// Durdurulmuş event'i yeniden başlatır (sadece admin)
+    pub fn unpause_event(env: &Env, caller: Address, event_id: u64) -> Result<bool, Error> {
+        Self::require_initialized(env)?;
+        Self::require_admin(env, &caller)?;
+        Self::extend_instance_ttl(env);
+        
+        Self::set_event_paused(env, event_id, false)?;
+        
+        env.events().publish(
+            (symbol_short!("event"), symbol_short!("unpaused"), event_id),
+            PausePayload {
+                version: EVENTS_VERSION,
+                by: caller,
+            },
+        );
+        
+        Ok(true)
+    }
+
+    /// Guardian adresini ayarlar veya kaldırır (sadece admin)
+    ///
+    /// Guardian contract'ı ve event'leri durdurabilir, ancak yeniden başlatamaz.
+    pub fn set_guardian(
+        env: &Env,
+        caller: Address,
+        guardian: Option<Address>,
+    ) -> Result<bool, Error> {
+        Self::require_initialized(env)?;
+        Self::require_admin(env, &caller)?;
+        Self::extend_instance_ttl(env);
+        
+        match &guardian {
+            Some(guardian) => env.storage().instance().set(&GUARDIAN_KEY, guardian),
+            None => env.storage().instance().remove(&GUARDIAN_KEY),
+        }
+        
+        env.events().publish(
+            (symbol_short!("guardian"), symbol_short!("changed")),
+            GuardianChangedPayload {
+                version: EVENTS_VERSION,
+                guardian,
+            },
+        );
+        
+        Ok(true)
+    }
+
+    /// Guardian adresini döndürür
+    pub fn get_guardian(env: &Env) -> Result<Option<Address>, Error> {
+        Self::require_initialized(env)?;
+        Ok(env.storage().instance().get(&GUARDIAN_KEY))
+    }
+
+    /// Çağıranın admin veya guardian olduğunu doğrular ve imzasını ister
+    fn require_pauser(env: &Env, caller: &Address) -> Result<(), Error> {
+        let guardian: Option<Address> = env.storage().instance().get(&GUARDIAN_KEY);
+        if guardian.as_ref() == Some(caller) {
+            caller.require_auth();
+            return Ok(());
+        }
+        Self::require_admin(env, caller)
+    }
+
+    /// Contract durdurulmuşsa `ContractPaused` hatası verir
+    fn require_not_paused(env: &Env) -> Result<(), Error> {
+        if env.storage().instance().get(&PAUSED_KEY).unwrap_or(false) {
+            return Err(Error::ContractPaused);
+        }
+        Ok(())
+    }
+
+    /// İptal edilmemiş event'in durdurulma durumunu günceller
+    fn set_event_paused(env: &Env, event_id: u64, is_paused: bool) -> Result<(), Error> {
+        let mut event = Self::load_event(env, event_id).ok_or(Error::EventNotFound)?;
+        if event.is_cancelled {
+            return Err(Error::EventCancelled);
+        }
+        
+        event.is_paused = is_paused;
+        Self::save_event(env, &event);
+        Ok(())
+    }
+
+    /// Admin konseyini kurar (sadece admin, konsey yokken)
+    ///
+    /// Kurulumdan sonra konsey üyeleri yalnızca `CouncilAction::SetCouncil`
+    /// önerisiyle değiştirilebilir. Bekleyen admin devri önerisi iptal edilir.
+    pub fn set_council(
+        env: &Env,
+        caller: Address,
+        members: Vec<Address>,
+        threshold: u32,
+    ) -> Result<bool, Error> {
+        Self::require_initialized(env)?;
+        Self::require_admin(env, &caller)?;
+        Self::require_no_council(env)?;
+        Self::extend_instance_ttl(env);
+        
+        env.storage().instance().remove(&PENDING_ADMIN_KEY);
+        Self::store_council(env, members, threshold, 0)?;
+        
+        Ok(true)
+    }
+
+    /// Admin konseyini döndürür
+    pub fn get_council(env: &Env) -> Result<Option<Council>, Error> {
+        Self::require_initialized(env)?;
+        Ok(env.storage().instance().get(&COUNCIL_KEY))
+    }
+
+    /// Konsey önerisi oluşturur ve önereni ilk onay olarak sayar (sadece konsey üyesi)
+    ///
+    /// Onay eşiğine ulaşan öneri aynı çağrıda uygulanır. Öneri kimliğini döndürür.
+    pub fn propose_council_action(
+        env: &Env,
+        caller: Address,
+        action: CouncilAction,
+        expires_at: u64,
+    ) -> Result<u64, Error> {
+        Self::require_initialized(env)?;
+        Self::extend_instance_ttl(env);
+        
+        let council = Self::require_council_member(env, &caller)?;
+        if env.ledger().timestamp() > expires_at {
+            return Err(Error::ProposalExpired);
+        }
+        
+        let proposal_id: u64 = env
+            .storage()
+            .instance()
+            .get(&PROPOSAL_COUNTER_KEY)
+            .unwrap_or(0)
+            + 1;
+        env.storage()
+            .instance()
+            .set(&PROPOSAL_COUNTER_KEY, &proposal_id);
+        
+        let proposal = CouncilProposal {
+            id: proposal_id,
+            action: action.clone(),
+            proposer: caller.clone(),
+            approvals: Vec::new(env),
+            expires_at,
+            epoch: council.epoch,
+            executed: false,
+        };
+        
+        env.events().publish(
+            (symbol_short!("council"), symbol_short!("proposed"), proposal_id),
+            CouncilProposedPayload {
+                version: EVENTS_VERSION,
+                proposer: caller.clone(),
+                action,
+                expires_at,
+            },
+        );
+        
+        Self::approve_proposal(env, &council, proposal, caller)?;
+        
+        Ok(proposal_id)
+    }
+
+    /// Konsey önerisini onaylar (sadece konsey üyesi)
+    ///
+    /// Onay eşiğine ulaşıldıysa öneri uygulanır ve `true` döner.
+    pub fn approve_council_action(
+        env: &Env,
+        caller: Address,
+        proposal_id: u64,
+    ) -> Result<bool, Error> {
+        Self::require_initialized(env)?;
+        Self::extend_instance_ttl(env);
+        
+        let council = Self::require_council_member(env, &caller)?;
+        let proposal: CouncilProposal = env
+            .storage()
+            .persistent()
+            .get(&DataKey::CouncilProposal(proposal_id))
+            .ok_or(Error::ProposalNotFound)?;
+        
+        if proposal.executed {
+            return Err(Error::ProposalExecuted);
+        }
+        if proposal.epoch != council.epoch || env.ledger().timestamp() > proposal.expires_at {
+            return Err(Error::ProposalExpired);
+        }
+        if proposal.approvals.contains(&caller) {
+            return Err(Error::AlreadyApproved);
+        }
+        
+        Self::approve_proposal(env, &council, proposal, caller)
+    }
+
+    /// Konsey önerisini döndürür
+    pub fn get_council_proposal(env: &Env, proposal_id: u64) -> Result<CouncilProposal, Error> {
+        Self::require_initialized(env)?;
+        env.storage()
+            .persistent()
+            .get(&DataKey::CouncilProposal(proposal_id))
+            .ok_or(Error::ProposalNotFound)
+    }
+
+    /// Öneriye üyenin onayını ekler, eşiğe ulaşıldıysa öneriyi uygular
+    fn approve_proposal(
+        env: &Env,
+        council: &Council,
+        mut proposal: CouncilProposal,
+        member: Address,
+    ) -> Result<bool, Error> {
+        proposal.approvals.push_back(member.clone());
+        
+        env.events().publish(
+            (symbol_short!("council"), symbol_short!("approved"), proposal.id),
+            CouncilApprovedPayload {
+                version: EVENTS_VERSION,
+                member,
+                approvals: proposal.approvals.len(),
+            },
+        );
+        
+        let executed = proposal.approvals.len() >= council.threshold;
+        if executed {
+            proposal.executed = true;
+            Self::execute_council_action(env, council, proposal.action.clone())?;
+            
+            env.events().publish(
+                (symbol_short!("council"), symbol_short!("executed"), proposal.id),
+                CouncilExecutedPayload {
+                    version: EVENTS_VERSION,
+                    action: proposal.action.clone(),
+                },
+            );
+        }
+        
+        let key = DataKey::CouncilProposal(proposal.id);
+        let config = Self::ttl_config(env);
+        env.storage().persistent().set(&key, &proposal);
+        env.storage().persistent().extend_ttl(
+            &key,
+            config.persistent_threshold,
+            config.persistent_extend_to,
+        );
+        
+        Ok(executed)
+    }
+
+    /// Onaylanan konsey işlemini uygular
+    fn execute_council_action(
+        env: &Env,
+        council: &Council,
+        action: CouncilAction,
+    ) -> Result<(), Error> {
+        match action {
+            CouncilAction::CancelEvent(event_id) => {
+                let event = Self::load_event(env, event_id).ok_or(Error::EventNotFound)?;
+                Self::cancel(env, event, REFUND_BATCH_SIZE)
+            }
+            CouncilAction::SetAdmin(new_admin) => Self::replace_admin(env, new_admin),
+            CouncilAction::SetCouncil(members, threshold) => {
+                Self::store_council(env, members, threshold, council.epoch + 1)
+            }
+            CouncilAction::WithdrawPlatformFees(to) => {
+                Self::pay_platform_fees(env, to).map(|_| ())
+            }
+            CouncilAction::SetHoldPeriod(seconds) => {
+                env.storage().instance().set(&HOLD_PERIOD_KEY, &seconds);
+                Ok(())
+            }
+            CouncilAction::SetPlatformFee(fee) => Self::store_platform_fee(env, fee),
+            CouncilAction::SetOrganizerFee(organizer, fee) => {
+                Self::store_organizer_fee(env, organizer, Some(fee))
+            }
+            CouncilAction::ClearOrganizerFee(organizer) => {
+                Self::store_organizer_fee(env, organizer, None)
+            }
+            CouncilAction::SetTtlConfig(config) => Self::store_ttl_config(env, config),
+        }
+    }
+
+    /// Konsey üyelerini doğrulayıp kaydeder
+    fn store_council(
+        env: &Env,
+        members: Vec<Address>,
+        threshold: u32,
+        epoch: u32,
+    ) -> Result<(), Error> {
+        if threshold == 0 || threshold > members.len() {
+            return Err(Error::InvalidCouncil);
+        }
+        for (i, member) in members.iter().enumerate() {
+            if members.first_index_of(&member) != Some(i as u32) {
+                return Err(Error::InvalidCouncil);
+            }
+        }
+        
+        let council = Council {
+            members: members.clone(),
+            threshold,
+            epoch,
+        };
+        env.storage().instance().set(&COUNCIL_KEY, &council);
+        
+        env.events().publish(
+            (symbol_short!("council"), symbol_short!("changed")),
+            CouncilChangedPayload {
+                version: EVENTS_VERSION,
+                members,
+                threshold,
+                epoch,
+            },
+        );
+        
+        Ok(())
+    }
+
+    /// Çağıranın konsey üyesi olduğunu doğrular, imzasını ister ve konseyi döndürür
+    fn require_council_member(env: &Env, caller: &Address) -> Result<Council, Error> {
+        let council: Council = env
+            .storage()
+            .instance()
+            .get(&COUNCIL_KEY)
+            .ok_or(Error::NotCouncilMember)?;
+        if !council.members.contains(caller) {
+            return Err(Error::NotCouncilMember);
+        }
+        caller.require_auth();
+        Ok(council)
+    }
+
+    /// Konsey kuruluysa `CouncilRequired` hatası verir
+    fn require_no_council(env: &Env) -> Result<(), Error> {
+        if env.storage().instance().has(&COUNCIL_KEY) {
+            return Err(Error::CouncilRequired);
+        }
+        Ok(())
+    }
+
+    /// Adrese event oluşturma yetkisi verir (sadece admin)
+    pub fn grant_organizer(env: &Env, caller: Address, organizer: Address) -> Result<bool, Error> {
+        Self::require_initialized(env)?;
+        Self::require_admin(env, &caller)?;
+        Self::extend_instance_ttl(env);
+        
+        let key = DataKey::Organizer(organizer.clone());
+        env.storage().persistent().set(&key, &true);
+        Self::extend_organizer_ttl(env, &organizer);
+        
+        env.events().publish(
+            (symbol_short!("organizer"), symbol_short!("granted")),
+            OrganizerChangedPayload {
+                version: EVENTS_VERSION,
+                organizer,
+            },
+        );
+        
+        Ok(true)
+    }
+
+    /// Adresin event oluşturma yetkisini kaldırır (sadece admin)
+    ///
+    /// Organizatörün mevcut event'leri etkilenmez; yalnızca yeni event oluşturamaz.
+    pub fn revoke_organizer(env: &Env, caller: Address, organizer: Address) -> Result<bool, Error> {
+        Self::require_initialized(env)?;
+        Self::require_admin(env, &caller)?;
+        Self::extend_instance_ttl(env);
+        
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Organizer(organizer.clone()));
+        
+        env.events().publish(
+            (symbol_short!("organizer"), symbol_short!("revoked")),
+            OrganizerChangedPayload {
+                version: EVENTS_VERSION,
+                organizer,
+            },
+        );
+        
+        Ok(true)
+    }
+
+    /// Adresin onaylı organizatör olup olmadığını döndürür
+    pub fn is_organizer(env: &Env, address: Address) -> Result<bool, Error> {
+        Self::require_initialized(env)?;
+        Ok(Self::organizer_registered(env, &address))
+    }
+
+    /// Organizatör kaydını okur, kayıt varsa TTL'ini uzatır
+    fn organizer_registered(env: &Env, address: &Address) -> bool {
+        let registered = env
+            .storage()
+            .persistent()
+            .has(&DataKey::Organizer(address.clone()));
+        if registered {
+            Self::extend_organizer_ttl(env, address);
+        }
+        registered
+    }
+
+    /// Event'e check-in görevlisi atar (sadece event organizatörü)
+    pub fn add_scanner(
+        env: &Env,
+        caller: Address,
+        event_id: u64,
+        scanner: Address,
+    ) -> Result<bool, Error> {
+        Self::require_initialized(env)?;
+        Self::extend_instance_ttl(env);
+        
+        let event = Self::load_event(env, event_id).ok_or(Error::EventNotFound)?;
+        Self::require_organizer(&event, &caller)?;
+        
+        env.storage()
+            .persistent()
+            .set(&DataKey::Scanner(event_id, scanner.clone()), &true);
+        Self::extend_scanner_ttl(env, event_id, &scanner);
+        
+        env.events().publish(
+            (symbol_short!("scanner"), symbol_short!("added"), event_id),
+            ScannerChangedPayload {
+                version: EVENTS_VERSION,
+                scanner,
+            },
+        );
+        
+        Ok(true)
+    }
+
+    /// Event'in check-in görevlisini kaldırır (sadece event organizatörü)
+    pub fn remove_scanner(
+        env: &Env,
+        caller: Address,
+        event_id: u64,
+        scanner: Address,
+    ) -> Result<bool, Error> {
+        Self::require_initialized(env)?;
+        Self::extend_instance_ttl(env);
+        
+        let event = Self::load_event(env, event_id).ok_or(Error::EventNotFound)?;
+        Self::require_organizer(&event, &caller)?;
+        
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Scanner(event_id, scanner.clone()));
+        
+        env.events().publish(
+            (symbol_short!("scanner"), symbol_short!("removed"), event_id),
+            ScannerChangedPayload {
+                version: EVENTS_VERSION,
+                scanner,
+            },
+        );
+        
+        Ok(true)
+    }
+
+    /// Adresin event'te check-in görevlisi olup olmadığını döndürür
+    pub fn is_scanner(env: &Env, event_id: u64, address: Address) -> Result<bool, Error> {
+        Self::require_initialized(env)?;
+        Ok(Self::scanner_registered(env, event_id, &address))
+    }
+
+    /// Görevli kaydını okur, kayıt varsa TTL'ini uzatır
+    fn scanner_registered(env: &Env, event_id: u64, address: &Address) -> bool {
+        let registered = env
+            .storage()
+            .persistent()
+            .has(&DataKey::Scanner(event_id, address.clone()));
+        if registered {
+            Self::extend_scanner_ttl(env, event_id, address);
+        }
+        registered
+    }
+
+    /// Görevli kaydının TTL'ini uzatır
+    fn extend_scanner_ttl(env: &Env, event_id: u64, scanner: &Address) {
+        let config = Self::ttl_config(env);
+        env.storage().persistent().extend_ttl(
+            &DataKey::Scanner(event_id, scanner.clone()),
+            config.persistent_threshold,
+            config.persistent_extend_to,
+        );
+    }
+
+    /// Organizatör kaydının TTL'ini uzatır
+    fn extend_organizer_ttl(env: &Env, organizer: &Address) {
+        let config = Self::ttl_config(env);
+        env.storage().persistent().extend_ttl(
+            &DataKey::Organizer(organizer.clone()),
+            config.persistent_threshold,
+            config.persistent_extend_to,
+        );
+    }
+
+    /// Organizatöre özel ücret kaydının TTL'ini uzatır
+    fn extend_organizer_fee_ttl(env: &Env, organizer: &Address) {
+        let config = Self::ttl_config(env);
+        env.storage().persistent().extend_ttl(
+            &DataKey::OrganizerFee(organizer.clone()),
+            config.persistent_threshold,
+            config.persistent_extend_to,
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use super::*;
+    use soroban_sdk::{
+        symbol_short, token, vec, xdr::ScAddress, Env, IntoVal, TryFromVal, Val,
+    };
+    use soroban_sdk::testutils::storage::{Instance as _, Persistent as _};
+    use soroban_sdk::testutils::{
+        Address as TestAddress, AuthorizedFunction, AuthorizedInvocation, EnvTestConfig, Events,
+        IssuerFlags, Ledger, MockAuth, MockAuthInvoke,
+    };
+
+    /// Test için Stellar asset contract'ı kaydeder ve adresini döndürür
+    fn create_token(env: &Env, admin: &Address) -> Address {
+        env.register_stellar_asset_contract_v2(admin.clone()).address()
+    }
+
+    /// Ledger durumunu yeni bir Env'e taşır
+    ///
+    /// Test host'u bir Env boyunca dokunulan tüm kayıtları tek bir storage map'inde
+    /// biriktirir. Yeni Env kayıtları snapshot'tan ihtiyaç oldukça yükler; böylece
+    /// sonraki çağrılar gerçek bir işlemdeki gibi yalnızca kendi kayıtlarına dokunur.
+    fn reload_env(env: &Env) -> Env {
+        let mut reloaded = Env::from_ledger_snapshot(env.to_ledger_snapshot());
+        reloaded.set_config(EnvTestConfig {
+            capture_snapshot_at_drop: false,
+        });
+        
+        // Her işlem yeni bir ledger'da çalışır; PRNG tohumu değişmezse mock auth nonce'ları çakışır
+        let sequence = env.ledger().sequence() + 1;
+        reloaded.ledger().set_sequence_number(sequence);
+        let mut seed = [0u8; 32];
+        seed[..4].copy_from_slice(&sequence.to_be_bytes());
+        reloaded.host().set_base_prng_seed(seed).unwrap();
+        reloaded.mock_all_auths_allowing_non_root_auth();
+        reloaded.budget().reset_unlimited();
+        reloaded
+    }
+
+    /// Auth testleri için bir event ve satılmış bir ticket hazırlar
+    ///
+    /// (contract, admin, alıcı, event ID, ticket ID) döndürür.
+    fn setup_ticket(env: &Env) -> (Address, Address, Address, u64, u64) {
+        env.mock_all_auths_allowing_non_root_auth();
+        let contract_id = env.register_contract(None, EventXContract);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(env);
+        let token = create_token(env, &admin);
+        let buyer = <soroban_sdk::Address as TestAddress>::generate(env);
+        token::StellarAssetClient::new(env, &token).mint(&buyer, &10_000);
+        
+        env.as_contract(&contract_id, || {
+            EventXContract::initialize(env, admin.clone(), token.clone()).unwrap();
+        });
+        
+        let event_params = CreateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 100,
+            ticket_price: 1000,
+            event_date: 1234567890,
+            tiers: Vec::new(env),
+        };
+        let event_id = env.as_contract(&contract_id, || {
+            EventXContract::create_event(env, admin.clone(), event_params).unwrap()
+        });
+        
+        let buy_params = BuyTicketParams {
+            event_id,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        let ticket_id = env.as_contract(&contract_id, || {
+            EventXContract::buy_ticket(env, buyer.clone(), buy_params).unwrap().first().unwrap()
+        });
+        
+        (contract_id, admin, buyer, event_id, ticket_id)
+    }
+
+    /// Adresi başka bir Env'de kullanılabilir hale getirir
+    fn move_address(env: &Env, address: &Address) -> Address {
+        Address::try_from_val(env, &ScAddress::from(address)).unwrap()
+    }
+
+    #[test]
+    fn test_initialize() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let contract_id = env.register_contract(None, EventXContract);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        
+        env.as_contract(&contract_id, || {
+            EventXContract::initialize(&env, admin.clone(), token.clone()).unwrap();
+        });
+        
+        let stored_admin = env.as_contract(&contract_id, || {
+            EventXContract::get_admin(&env).unwrap()
+        });
+        assert_eq!(stored_admin, admin);
+    }
+
+    #[test]
+    fn test_create_event() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let contract_id = env.register_contract(None, EventXContract);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        
+        env.as_contract(&contract_id, || {
+            EventXContract::initialize(&env, admin.clone(), token.clone()).unwrap();
+        });
+        
+        let params = CreateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 100,
+            ticket_price: 1000,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        
+        let event_id = env.as_contract(&contract_id, || {
+            EventXContract::create_event(&env, admin, params).unwrap()
+        });
+        
+        let event = env.as_contract(&contract_id, || {
+            EventXContract::get_event(&env, event_id)
+        }).unwrap();
+        
+        assert_eq!(event.title, symbol_short!("TestEvent"));
+        assert_eq!(event.total_tickets, 100);
+        assert_eq!(event.tickets_sold, 0);
+        assert_eq!(event.ticket_price, 1000);
+        assert!(event.is_active);
+        assert!(!event.is_cancelled);
+    }
+
+    #[test]
+    fn test_buy_ticket() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let contract_id = env.register_contract(None, EventXContract);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        let buyer = <soroban_sdk::Address as TestAddress>::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&buyer, &10_000);
+        
+        env.as_contract(&contract_id, || {
+            EventXContract::initialize(&env, admin.clone(), token.clone()).unwrap();
+        });
+        
+        let event_params = CreateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 100,
+            ticket_price: 1000,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        
+        let event_id = env.as_contract(&contract_id, || {
+            EventXContract::create_event(&env, admin, event_params).unwrap()
+        });
+        
+        let buy_params = BuyTicketParams {
+            event_id,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        
+        let ticket_id = env.as_contract(&contract_id, || {
+            EventXContract::buy_ticket(&env, buyer.clone(), buy_params).unwrap().first().unwrap()
+        });
+        
+        let ticket = env.as_contract(&contract_id, || {
+            EventXContract::get_ticket(&env, ticket_id)
+        }).unwrap();
+        
+        assert_eq!(ticket.owner, buyer);
+        assert_eq!(ticket.event_id, event_id);
+        assert!(!ticket.is_used);
+        assert!(!ticket.is_refunded);
+        assert_eq!(ticket.price_paid, 1000);
+        
+        let ticket_count = env.as_contract(&contract_id, || {
+            EventXContract::get_event_ticket_count(&env, event_id).unwrap()
+        });
+        assert_eq!(ticket_count, 1);
+        
+        // Ücret alıcıdan contract'a geçmiş ve escrow'a yazılmış olmalı
+        let token_client = token::Client::new(&env, &token);
+        assert_eq!(token_client.balance(&buyer), 9_000);
+        assert_eq!(token_client.balance(&contract_id), 1000);
+        
+        let escrow = env.as_contract(&contract_id, || {
+            EventXContract::get_event_escrow(&env, event_id).unwrap()
+        });
+        assert_eq!(escrow, 1000);
+    }
+
+    #[test]
+    #[should_panic(expected = "balance is not sufficient to spend")]
+    fn test_buy_ticket_insufficient_balance() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let contract_id = env.register_contract(None, EventXContract);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        let buyer = <soroban_sdk::Address as TestAddress>::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&buyer, &500);
+        
+        client.initialize(&admin, &token);
+        
+        let event_params = CreateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 100,
+            ticket_price: 1000,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        let event_id = client.create_event(&admin, &event_params);
+        
+        let buy_params = BuyTicketParams {
+            event_id,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        
+        // Token transferi bakiye yetersiz olduğu için başarısız olur
+        client.buy_ticket(&buyer, &buy_params);
+    }
+
+    #[test]
+    fn test_get_all_events() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let contract_id = env.register_contract(None, EventXContract);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        
+        env.as_contract(&contract_id, || {
+            EventXContract::initialize(&env, admin.clone(), token.clone()).unwrap();
+        });
+        
+        let event_params1 = CreateEventParams {
+            title: symbol_short!("Event1"),
+            description: symbol_short!("Desc1"),
+            total_tickets: 50,
+            ticket_price: 500,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        
+        env.as_contract(&contract_id, || {
+            EventXContract::create_event(&env, admin.clone(), event_params1).unwrap()
+        });
+        
+        let event_params2 = CreateEventParams {
+            title: symbol_short!("Event2"),
+            description: symbol_short!("Desc2"),
+            total_tickets: 100,
+            ticket_price: 1000,
+            event_date: 1234567891,
+            tiers: Vec::new(&env),
+        };
+        
+        env.as_contract(&contract_id, || {
+            EventXContract::create_event(&env, admin, event_params2).unwrap()
+        });
+        
+        let all_events = env.as_contract(&contract_id, || {
+            EventXContract::get_all_events(&env).unwrap()
+        });
+        
+        assert_eq!(all_events.len(), 2);
+        let mut found_event1 = false;
+        let mut found_event2 = false;
+        for i in 0..all_events.len() {
+            let event = all_events.get(i).unwrap();
+            if event.title == symbol_short!("Event1") {
+                found_event1 = true;
+            }
+            if event.title == symbol_short!("Event2") {
+                found_event2 = true;
+            }
+        }
+        assert!(found_event1);
+        assert!(found_event2);
+    }
+
+    #[test]
+    fn test_create_many_events() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let contract_id = env.register_contract(None, EventXContract);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        
+        env.as_contract(&contract_id, || {
+            EventXContract::initialize(&env, admin.clone(), token.clone()).unwrap();
+        });
+        
+        // On event sınırının ötesinde her event kendi ID'sini almalı
+        for i in 0..25u64 {
+            let event_params = CreateEventParams {
+                title: symbol_short!("TestEvent"),
+                description: symbol_short!("TestDesc"),
+                total_tickets: 10,
+                ticket_price: 100,
+                event_date: 1234567890 + i,
+                tiers: Vec::new(&env),
+            };
+            let event_id = env.as_contract(&contract_id, || {
+                EventXContract::create_event(&env, admin.clone(), event_params).unwrap()
+            });
+            assert_eq!(event_id, i + 1);
+        }
+        
+        let all_events = env.as_contract(&contract_id, || {
+            EventXContract::get_all_events(&env).unwrap()
+        });
+        
+        assert_eq!(all_events.len(), 25);
+        for (i, event) in all_events.iter().enumerate() {
+            assert_eq!(event.id, i as u64 + 1);
+            assert_eq!(event.event_date, 1234567890 + i as u64);
+        }
+    }
+
+    #[test]
+    fn test_list_events() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EventXContract);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let organizer = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        client.initialize(&admin, &token);
+        
+        for i in 1..=5u64 {
+            let event_params = CreateEventParams {
+                title: symbol_short!("TestEvent"),
+                description: symbol_short!("TestDesc"),
+                total_tickets: 100,
+                ticket_price: 1000,
+                event_date: i * 1000,
+                tiers: Vec::new(&env),
+            };
+            client.create_event(&admin, &event_params);
+        }
+        client.cancel_event(&admin, &4, &0);
+        
+        // Farklı organizatörün event'i
+        client.grant_organizer(&admin, &organizer);
+        let event_params = CreateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 100,
+            ticket_price: 1000,
+            event_date: 6000,
+            tiers: Vec::new(&env),
+        };
+        client.create_event(&organizer, &event_params);
+        
+        env.ledger().with_mut(|li| {
+            li.timestamp = 2500;
+        });
+        
+        let ids = |page: EventPage| -> std::vec::Vec<u64> {
+            page.events.iter().map(|event| event.id).collect()
+        };
+        let filter = EventFilter {
+            statuses: Vec::new(&env),
+            organizer: None,
+            date_after: None,
+            date_before: None,
+        };
+        
+        // Sayfalama
+        let page = client.list_events(&0, &0, &filter);
+        assert_eq!(page.next_cursor, None);
+        assert_eq!(ids(page), std::vec![1, 2, 3, 4, 5, 6]);
+        let page = client.list_events(&0, &4, &filter);
+        assert_eq!(page.next_cursor, Some(4));
+        assert_eq!(ids(page), std::vec![1, 2, 3, 4]);
+        let page = client.list_events(&4, &4, &filter);
+        assert_eq!(page.next_cursor, None);
+        assert_eq!(ids(page), std::vec![5, 6]);
+        
+        // Durum filtresi
+        let by_status = |status: EventStatus| EventFilter {
+            statuses: vec![&env, status],
+            ..filter.clone()
+        };
+        let active = by_status(EventStatus::Active);
+        assert_eq!(ids(client.list_events(&0, &0, &active)), std::vec![3, 5, 6]);
+        let cancelled = by_status(EventStatus::Cancelled);
+        assert_eq!(ids(client.list_events(&0, &0, &cancelled)), std::vec![4]);
+        let completed = by_status(EventStatus::Completed);
+        assert_eq!(ids(client.list_events(&0, &0, &completed)), std::vec![1, 2]);
+        
+        // Filtreye uymayan kayıtlar da limite sayılır
+        let page = client.list_events(&0, &2, &active);
+        assert!(page.events.is_empty());
+        assert_eq!(page.next_cursor, Some(2));
+        
+        // Organizatör filtresi
+        let by_organizer = EventFilter {
+            organizer: Some(organizer.clone()),
+            ..filter.clone()
+        };
+        assert_eq!(ids(client.list_events(&0, &0, &by_organizer)), std::vec![6]);
+        
+        // Tarih aralığı [date_after, date_before)
+        let by_date = EventFilter {
+            date_after: Some(2000),
+            date_before: Some(5000),
+            ..filter.clone()
+        };
+        assert_eq!(ids(client.list_events(&0, &0, &by_date)), std::vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn test_event_and_ticket_counters_are_independent() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let contract_id = env.register_contract(None, EventXContract);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        let buyer = <soroban_sdk::Address as TestAddress>::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&buyer, &10_000);
+        
+        env.as_contract(&contract_id, || {
+            EventXContract::initialize(&env, admin.clone(), token.clone()).unwrap();
+        });
+        
+        let event_params = CreateEventParams {
+            title: symbol_short!("Event1"),
+            description: symbol_short!("Desc1"),
+            total_tickets: 10,
+            ticket_price: 100,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        let first_event = env.as_contract(&contract_id, || {
+            EventXContract::create_event(&env, admin.clone(), event_params).unwrap()
+        });
+        
+        for expected_serial in 1..=3u32 {
+            let buy_params = BuyTicketParams {
+                event_id: first_event,
+                buyer: buyer.clone(),
+                tier: None,
+                seat: TicketSeat::Unassigned,
+                quantity: 1,
+            };
+            let ticket_id = env.as_contract(&contract_id, || {
+                EventXContract::buy_ticket(&env, buyer.clone(), buy_params).unwrap().first().unwrap()
+            });
+            let ticket = env.as_contract(&contract_id, || {
+                EventXContract::get_ticket(&env, ticket_id)
+            }).unwrap();
+            assert_eq!(ticket.serial, expected_serial);
+        }
+        
+        // Ticket satışları bir sonraki event ID'sini etkilememeli
+        let event_params = CreateEventParams {
+            title: symbol_short!("Event2"),
+            description: symbol_short!("Desc2"),
+            total_tickets: 10,
+            ticket_price: 100,
+            event_date: 1234567891,
+            tiers: Vec::new(&env),
+        };
+        let second_event = env.as_contract(&contract_id, || {
+            EventXContract::create_event(&env, admin.clone(), event_params).unwrap()
+        });
+        assert_eq!(second_event, first_event + 1);
+        
+        let buy_params = BuyTicketParams {
+            event_id: second_event,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        let ticket_id = env.as_contract(&contract_id, || {
+            EventXContract::buy_ticket(&env, buyer.clone(), buy_params).unwrap().first().unwrap()
+        });
+        let ticket = env.as_contract(&contract_id, || {
+            EventXContract::get_ticket(&env, ticket_id)
+        }).unwrap();
+        assert_eq!(ticket.id, 4);
+        assert_eq!(ticket.serial, 1);
+        
+        env.as_contract(&contract_id, || {
+            assert_eq!(EventXContract::event_count(&env).unwrap(), 2);
+            assert_eq!(EventXContract::ticket_count(&env).unwrap(), 4);
+            assert_eq!(EventXContract::event_ticket_serial(&env, first_event).unwrap(), 3);
+            assert_eq!(EventXContract::event_ticket_serial(&env, second_event).unwrap(), 1);
+        });
+    }
+
+    #[test]
+    fn test_transfer_ticket() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let contract_id = env.register_contract(None, EventXContract);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        let buyer = <soroban_sdk::Address as TestAddress>::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&buyer, &10_000);
+        let new_owner = <soroban_sdk::Address as TestAddress>::generate(&env);
+        
+        env.as_contract(&contract_id, || {
+            EventXContract::initialize(&env, admin.clone(), token.clone()).unwrap();
+        });
+        
+        let event_params = CreateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 100,
+            ticket_price: 1000,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        
+        let event_id = env.as_contract(&contract_id, || {
+            EventXContract::create_event(&env, admin, event_params).unwrap()
+        });
+        
+        let buy_params = BuyTicketParams {
+            event_id,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        
+        let ticket_id = env.as_contract(&contract_id, || {
+            EventXContract::buy_ticket(&env, buyer.clone(), buy_params).unwrap().first().unwrap()
+        });
+        
+        let transfer_params = TransferTicketParams {
+            ticket_id,
+            from: buyer.clone(),
+            to: new_owner.clone(),
+        };
+        
+        let result = env.as_contract(&contract_id, || {
+            EventXContract::transfer_ticket(&env, buyer, transfer_params).unwrap()
+        });
+        assert!(result);
+        
+        let ticket = env.as_contract(&contract_id, || {
+            EventXContract::get_ticket(&env, ticket_id)
+        }).unwrap();
+        
+        assert_eq!(ticket.owner, new_owner);
+    }
+
+    #[test]
+    fn test_use_ticket() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let contract_id = env.register_contract(None, EventXContract);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        let buyer = <soroban_sdk::Address as TestAddress>::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&buyer, &10_000);
+        
+        env.as_contract(&contract_id, || {
+            EventXContract::initialize(&env, admin.clone(), token.clone()).unwrap();
+        });
+        
+        let event_params = CreateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 100,
+            ticket_price: 1000,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        
+        let event_id = env.as_contract(&contract_id, || {
+            EventXContract::create_event(&env, admin.clone(), event_params).unwrap()
+        });
+        
+        let buy_params = BuyTicketParams {
+            event_id,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        
+        let ticket_id = env.as_contract(&contract_id, || {
+            EventXContract::buy_ticket(&env, buyer, buy_params).unwrap().first().unwrap()
+        });
+        
+        let result = env.as_contract(&contract_id, || {
+            EventXContract::use_ticket(&env, admin, ticket_id).unwrap()
+        });
+        assert!(result);
+        
+        let ticket = env.as_contract(&contract_id, || {
+            EventXContract::get_ticket(&env, ticket_id)
+        }).unwrap();
+        
+        assert!(ticket.is_used);
+    }
+
+    #[test]
+    fn test_cancel_event() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let contract_id = env.register_contract(None, EventXContract);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        let buyer = <soroban_sdk::Address as TestAddress>::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&buyer, &10_000);
+        
+        env.as_contract(&contract_id, || {
+            EventXContract::initialize(&env, admin.clone(), token.clone()).unwrap();
+        });
+        
+        let event_params = CreateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 100,
+            ticket_price: 1000,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        
+        let event_id = env.as_contract(&contract_id, || {
+            EventXContract::create_event(&env, admin.clone(), event_params).unwrap()
+        });
+        
+        let buy_params = BuyTicketParams {
+            event_id,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        
+        let ticket_id = env.as_contract(&contract_id, || {
+            EventXContract::buy_ticket(&env, buyer.clone(), buy_params).unwrap().first().unwrap()
+        });
+        
+        let result = env.as_contract(&contract_id, || {
+            EventXContract::cancel_event(&env, admin, event_id, 0).unwrap()
+        });
+        assert!(result);
+        
+        let event = env.as_contract(&contract_id, || {
+            EventXContract::get_event(&env, event_id)
+        }).unwrap();
+        
+        assert!(event.is_cancelled);
+        assert!(!event.is_active);
+        assert_eq!(event.escrow_balance, 0);
+        
+        // Ödenen tutar alıcıya geri dönmüş olmalı
+        let ticket = env.as_contract(&contract_id, || {
+            EventXContract::get_ticket(&env, ticket_id)
+        }).unwrap();
+        assert!(ticket.is_refunded);
+        
+        let token_client = token::Client::new(&env, &token);
+        assert_eq!(token_client.balance(&buyer), 10_000);
+        assert_eq!(token_client.balance(&contract_id), 0);
+    }
+
+    #[test]
+    fn test_cancel_event_refunds_in_batches() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let contract_id = env.register_contract(None, EventXContract);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        let buyer = <soroban_sdk::Address as TestAddress>::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&buyer, &100_000);
+        
+        env.as_contract(&contract_id, || {
+            EventXContract::initialize(&env, admin.clone(), token.clone()).unwrap();
+        });
+        
+        let event_params = CreateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 100,
+            ticket_price: 100,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        
+        let event_id = env.as_contract(&contract_id, || {
+            EventXContract::create_event(&env, admin.clone(), event_params).unwrap()
+        });
+        
+        for _ in 0..(REFUND_BATCH_SIZE + 5) {
+            let buy_params = BuyTicketParams {
+                event_id,
+                buyer: buyer.clone(),
+                tier: None,
+                seat: TicketSeat::Unassigned,
+                quantity: 1,
+            };
+            env.as_contract(&contract_id, || {
+                EventXContract::buy_ticket(&env, buyer.clone(), buy_params).unwrap()
+            });
+        }
+        
+        env.as_contract(&contract_id, || {
+            EventXContract::cancel_event(&env, admin, event_id, 5).unwrap()
+        });
+        
+        // İlk batch cancel_event içinde verilen limitle işlenir, kalanlar bekler
+        let pending = env.as_contract(&contract_id, || {
+            EventXContract::get_pending_refunds(&env, event_id).unwrap()
+        });
+        assert_eq!(pending, REFUND_BATCH_SIZE);
+        
+        let remaining = env.as_contract(&contract_id, || {
+            EventXContract::process_refunds(&env, event_id, 3).unwrap()
+        });
+        assert_eq!(remaining, REFUND_BATCH_SIZE - 3);
+        
+        let remaining = env.as_contract(&contract_id, || {
+            EventXContract::process_refunds(&env, event_id, 0).unwrap()
+        });
+        assert_eq!(remaining, 0);
+        
+        let event = env.as_contract(&contract_id, || {
+            EventXContract::get_event(&env, event_id)
+        }).unwrap();
+        assert_eq!(event.refund_cursor, REFUND_BATCH_SIZE + 5);
+        assert_eq!(event.escrow_balance, 0);
+        
+        let token_client = token::Client::new(&env, &token);
+        assert_eq!(token_client.balance(&buyer), 100_000);
+        assert_eq!(token_client.balance(&contract_id), 0);
+    }
+
+    #[test]
+    fn test_refund_batch_skips_failed_transfer() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EventXContract);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let buyer = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let blocked = <soroban_sdk::Address as TestAddress>::generate(&env);
+        
+        // Yetkisi kaldırılabilen token ile kurulum
+        let asset = env.register_stellar_asset_contract_v2(admin.clone());
+        asset.issuer().set_flag(IssuerFlags::RevocableFlag);
+        let token = asset.address();
+        let token_client = token::Client::new(&env, &token);
+        let token_admin = token::StellarAssetClient::new(&env, &token);
+        token_admin.mint(&buyer, &10_000);
+        token_admin.mint(&blocked, &10_000);
+        client.initialize(&admin, &token);
+        
+        let event_params = CreateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 100,
+            ticket_price: 1000,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        let event_id = client.create_event(&admin, &event_params);
+        let buy_params = BuyTicketParams {
+            event_id,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        let first_ticket = client.buy_ticket(&buyer, &buy_params).first().unwrap();
+        let buy_params = BuyTicketParams {
+            buyer: blocked.clone(),
+            ..buy_params
+        };
+        let blocked_ticket = client.buy_ticket(&blocked, &buy_params).first().unwrap();
+        
+        // Yetkisi kaldırılan alıcı batch'i durdurmaz, sadece atlanır
+        token_admin.set_authorized(&blocked, &false);
+        client.cancel_event(&admin, &event_id, &0);
+        assert!(client.get_ticket(&first_ticket).is_refunded);
+        assert!(!client.get_ticket(&blocked_ticket).is_refunded);
+        assert_eq!(client.get_pending_refunds(&event_id), 0);
+        assert_eq!(token_client.balance(&buyer), 10_000);
+        assert_eq!(client.get_event_escrow(&event_id), 1000);
+        
+        // Alıcı refund'unu yetkisi geri gelince kendisi talep eder
+        assert_eq!(
+            client.try_claim_refund(&blocked_ticket),
+            Err(Ok(Error::RefundFailed))
+        );
+        token_admin.set_authorized(&blocked, &true);
+        assert_eq!(client.claim_refund(&blocked_ticket), 1000);
+        assert_eq!(token_client.balance(&blocked), 10_000);
+        assert_eq!(token_client.balance(&contract_id), 0);
+    }
+
+    #[test]
+    fn test_process_refunds_requires_cancelled_event() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let contract_id = env.register_contract(None, EventXContract);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        
+        env.as_contract(&contract_id, || {
+            EventXContract::initialize(&env, admin.clone(), token.clone()).unwrap();
+        });
+        
+        let event_params = CreateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 100,
+            ticket_price: 1000,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        
+        let event_id = env.as_contract(&contract_id, || {
+            EventXContract::create_event(&env, admin, event_params).unwrap()
+        });
+        
+        let result = env.as_contract(&contract_id, || {
+            EventXContract::process_refunds(&env, event_id, 10)
+        });
+        assert_eq!(result, Err(Error::EventNotCancelled));
+    }
+
+    #[test]
+    fn test_ticket_ids_are_unique() {
+        let mut env = Env::new_with_config(EnvTestConfig {
+            capture_snapshot_at_drop: false,
+        });
+        env.mock_all_auths_allowing_non_root_auth();
+        env.budget().reset_unlimited();
+        let contract_id = env.register_contract(None, EventXContract);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        let buyer = <soroban_sdk::Address as TestAddress>::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&buyer, &1_000_000);
+        
+        env.as_contract(&contract_id, || {
+            EventXContract::initialize(&env, admin.clone(), token.clone()).unwrap();
+        });
+        
+        let mut event_ids = std::vec::Vec::new();
+        for _ in 0..4 {
+            let event_params = CreateEventParams {
+                title: symbol_short!("TestEvent"),
+                description: symbol_short!("TestDesc"),
+                total_tickets: 2000,
+                ticket_price: 10,
+                event_date: 1234567890,
+                tiers: Vec::new(&env),
+            };
+            let event_id = env.as_contract(&contract_id, || {
+                EventXContract::create_event(&env, admin.clone(), event_params).unwrap()
+            });
+            event_ids.push(event_id);
+        }
+        
+        // Event'ler arasında dönüşümlü olarak 2000 ticket satın al
+        let mut ticket_ids = std::vec::Vec::new();
+        for round in 0..4 {
+            env = reload_env(&env);
+            let buyer = move_address(&env, &buyer);
+            for i in 0..500 {
+                let event_id = event_ids[(round * 500 + i) % event_ids.len()];
+                let buy_params = BuyTicketParams {
+                    event_id,
+                    buyer: buyer.clone(),
+                    tier: None,
+                    seat: TicketSeat::Unassigned,
+                    quantity: 1,
+                };
+                let ticket_id = env.as_contract(&contract_id, || {
+                    EventXContract::buy_ticket(&env, buyer.clone(), buy_params).unwrap().first().unwrap()
+                });
+                ticket_ids.push(ticket_id);
+            }
+        }
+        
+        // Tüm ticket'lar kendi ID'leriyle okunabilmeli
+        for (chunk_index, chunk) in ticket_ids.chunks(500).enumerate() {
+            env = reload_env(&env);
+            let mut ids = Vec::new(&env);
+            for ticket_id in chunk {
+                ids.push_back(*ticket_id);
+            }
+            
+            let tickets = env.as_contract(&contract_id, || {
+                EventXContract::get_tickets(&env, ids).unwrap()
+            });
+            assert_eq!(tickets.len(), 500);
+            
+            for (i, ticket) in tickets.iter().enumerate() {
+                let position = chunk_index * 500 + i;
+                assert_eq!(ticket.id, position as u64 + 1);
+                assert_eq!(ticket.event_id, event_ids[position % event_ids.len()]);
+            }
+        }
+        
+        env = reload_env(&env);
+        for event_id in event_ids {
+            let ticket_count = env.as_contract(&contract_id, || {
+                EventXContract::get_event_ticket_count(&env, event_id).unwrap()
+            });
+            assert_eq!(ticket_count, 500);
+        }
+        env.as_contract(&contract_id, || {
+            assert_eq!(EventXContract::ticket_count(&env).unwrap(), 2000);
+        });
+    }
+
+    #[test]
+    fn test_buy_ticket_cost_stays_flat() {
+        let mut env = Env::new_with_config(EnvTestConfig {
+            capture_snapshot_at_drop: false,
+        });
+        env.mock_all_auths_allowing_non_root_auth();
+        env.budget().reset_unlimited();
+        let contract_id = env.register_contract(None, EventXContract);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        let buyer = <soroban_sdk::Address as TestAddress>::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&buyer, &1_000_000);
+        
+        env.as_contract(&contract_id, || {
+            EventXContract::initialize(&env, admin.clone(), token.clone()).unwrap();
+        });
+        
+        let event_params = CreateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 2000,
+            ticket_price: 10,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        let event_id = env.as_contract(&contract_id, || {
+            EventXContract::create_event(&env, admin, event_params).unwrap()
+        });
+        
+        // Tek bir buy_ticket çağrısının maliyetini yeni bir işlemdeymiş gibi ölçer
+        let measure = |env: &Env| {
+            let env = reload_env(env);
+            let buyer = move_address(&env, &buyer);
+            let buy_params = BuyTicketParams {
+                event_id,
+                buyer: buyer.clone(),
+                tier: None,
+                seat: TicketSeat::Unassigned,
+                quantity: 1,
+            };
+            env.budget().reset_default();
+            env.as_contract(&contract_id, || {
+                EventXContract::buy_ticket(&env, buyer, buy_params).unwrap()
+            });
+            (env.budget().cpu_instruction_cost(), env.budget().memory_bytes_cost())
+        };
+        
+        let (early_cpu, early_mem) = measure(&env);
+        
+        for _ in 0..2 {
+            env = reload_env(&env);
+            let buyer = move_address(&env, &buyer);
+            for _ in 0..500 {
+                let buy_params = BuyTicketParams {
+                    event_id,
+                    buyer: buyer.clone(),
+                    tier: None,
+                    seat: TicketSeat::Unassigned,
+                    quantity: 1,
+                };
+                env.as_contract(&contract_id, || {
+                    EventXContract::buy_ticket(&env, buyer.clone(), buy_params).unwrap()
+                });
+            }
+        }
+        
+        let (late_cpu, late_mem) = measure(&env);
+        
+        // 1000 ticket sonra maliyet %5'ten fazla artmamalı
+        assert!(late_cpu * 100 <= early_cpu * 105, "cpu: {} -> {}", early_cpu, late_cpu);
+        assert!(late_mem * 100 <= early_mem * 105, "mem: {} -> {}", early_mem, late_mem);
+    }
+
+    #[test]
+    fn test_claim_refund() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let contract_id = env.register_contract(None, EventXContract);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        let buyer = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let late_buyer = <soroban_sdk::Address as TestAddress>::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&buyer, &100_000);
+        token::StellarAssetClient::new(&env, &token).mint(&late_buyer, &10_000);
+        
+        env.as_contract(&contract_id, || {
+            EventXContract::initialize(&env, admin.clone(), token.clone()).unwrap();
+        });
+        
+        let event_params = CreateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 100,
+            ticket_price: 1000,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        
+        let event_id = env.as_contract(&contract_id, || {
+            EventXContract::create_event(&env, admin.clone(), event_params).unwrap()
+        });
+        
+        // İlk refund batch'ini dolduracak kadar ticket sat
+        for _ in 0..REFUND_BATCH_SIZE {
+            let buy_params = BuyTicketParams {
+                event_id,
+                buyer: buyer.clone(),
+                tier: None,
+                seat: TicketSeat::Unassigned,
+                quantity: 1,
+            };
+            env.as_contract(&contract_id, || {
+                EventXContract::buy_ticket(&env, buyer.clone(), buy_params).unwrap()
+            });
+        }
+        
+        let buy_params = BuyTicketParams {
+            event_id,
+            buyer: late_buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        let ticket_id = env.as_contract(&contract_id, || {
+            EventXContract::buy_ticket(&env, late_buyer.clone(), buy_params).unwrap().first().unwrap()
+        });
+        
+        env.as_contract(&contract_id, || {
+            EventXContract::cancel_event(&env, admin, event_id, 0).unwrap()
+        });
+        
+        // Son ticket ilk batch'in dışında kaldı, sahibi kendisi talep eder
+        let amount = env.as_contract(&contract_id, || {
+            EventXContract::claim_refund(&env, ticket_id).unwrap()
+        });
+        assert_eq!(amount, 1000);
+        
+        let ticket = env.as_contract(&contract_id, || {
+            EventXContract::get_ticket(&env, ticket_id)
+        }).unwrap();
+        assert!(ticket.is_refunded);
+        assert_eq!(token::Client::new(&env, &token).balance(&late_buyer), 10_000);
+        
+        // Sonraki batch zaten refund edilmiş ticket'ı atlar
+        let remaining = env.as_contract(&contract_id, || {
+            EventXContract::process_refunds(&env, event_id, 0).unwrap()
+        });
+        assert_eq!(remaining, 0);
+        
+        let escrow = env.as_contract(&contract_id, || {
+            EventXContract::get_event_escrow(&env, event_id).unwrap()
+        });
+        assert_eq!(escrow, 0);
+        assert_eq!(token::Client::new(&env, &token).balance(&contract_id), 0);
+    }
+
+    #[test]
+    fn test_claim_refund_requires_cancelled_event() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let contract_id = env.register_contract(None, EventXContract);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        let buyer = <soroban_sdk::Address as TestAddress>::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&buyer, &10_000);
+        
+        env.as_contract(&contract_id, || {
+            EventXContract::initialize(&env, admin.clone(), token.clone()).unwrap();
+        });
+        
+        let event_params = CreateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 100,
+            ticket_price: 1000,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        
+        let event_id = env.as_contract(&contract_id, || {
+            EventXContract::create_event(&env, admin, event_params).unwrap()
+        });
+        
+        let buy_params = BuyTicketParams {
+            event_id,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        
+        let ticket_id = env.as_contract(&contract_id, || {
+            EventXContract::buy_ticket(&env, buyer, buy_params).unwrap().first().unwrap()
+        });
+        
+        let result = env.as_contract(&contract_id, || {
+            EventXContract::claim_refund(&env, ticket_id)
+        });
+        assert_eq!(result, Err(Error::EventNotCancelled));
+    }
+
+    #[test]
+    fn test_claim_refund_twice() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let contract_id = env.register_contract(None, EventXContract);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        let buyer = <soroban_sdk::Address as TestAddress>::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&buyer, &10_000);
+        
+        env.as_contract(&contract_id, || {
+            EventXContract::initialize(&env, admin.clone(), token.clone()).unwrap();
+        });
+        
+        let event_params = CreateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 100,
+            ticket_price: 1000,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        
+        let event_id = env.as_contract(&contract_id, || {
+            EventXContract::create_event(&env, admin.clone(), event_params).unwrap()
+        });
+        
+        let buy_params = BuyTicketParams {
+            event_id,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        
+        let ticket_id = env.as_contract(&contract_id, || {
+            EventXContract::buy_ticket(&env, buyer, buy_params).unwrap().first().unwrap()
+        });
+        
+        // İptal ilk batch'te ticket'ı zaten refund eder
+        env.as_contract(&contract_id, || {
+            EventXContract::cancel_event(&env, admin, event_id, 0).unwrap()
+        });
+        
+        let result = env.as_contract(&contract_id, || {
+            EventXContract::claim_refund(&env, ticket_id)
+        });
+        assert_eq!(result, Err(Error::AlreadyRefunded));
+    }
+
+    #[test]
+    fn test_storage_ttl_extended_on_access() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let contract_id = env.register_contract(None, EventXContract);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        let buyer = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let new_owner = <soroban_sdk::Address as TestAddress>::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&buyer, &10_000);
+        
+        env.as_contract(&contract_id, || {
+            EventXContract::initialize(&env, admin.clone(), token.clone()).unwrap();
+        });
+        
+        let event_params = CreateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 100,
+            ticket_price: 1000,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        
+        let event_id = env.as_contract(&contract_id, || {
+            EventXContract::create_event(&env, admin.clone(), event_params).unwrap()
+        });
+        
+        let buy_params = BuyTicketParams {
+            event_id,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        
+        let ticket_id = env.as_contract(&contract_id, || {
+            EventXContract::buy_ticket(&env, buyer.clone(), buy_params).unwrap().first().unwrap()
+        });
+        
+        env.as_contract(&contract_id, || {
+            assert_eq!(env.storage().instance().get_ttl(), INSTANCE_EXTEND_TO);
+            assert_eq!(
+                env.storage().persistent().get_ttl(&DataKey::Event(event_id)),
+                PERSISTENT_EXTEND_TO
+            );
+            assert_eq!(
+                env.storage().persistent().get_ttl(&DataKey::Ticket(ticket_id)),
+                PERSISTENT_EXTEND_TO
+            );
+        });
+        
+        // Daha kısa TTL ayarları ile erişimde yeni değere uzatılmalı
+        let config = TtlConfig {
+            instance_threshold: 100,
+            instance_extend_to: 1000,
+            persistent_threshold: PERSISTENT_EXTEND_TO,
+            persistent_extend_to: PERSISTENT_EXTEND_TO + 2000,
+            archive_after: ARCHIVE_AFTER,
+        };
+        env.as_contract(&contract_id, || {
+            EventXContract::set_ttl_config(&env, admin.clone(), config).unwrap()
+        });
+        
+        // Kalan TTL'ler eşiklerin altına düşene kadar ledger'ı ilerlet
+        env.ledger().with_mut(|li| {
+            li.sequence_number += INSTANCE_EXTEND_TO - 50;
+        });
+        
+        let transfer_params = TransferTicketParams {
+            ticket_id,
+            from: buyer.clone(),
+            to: new_owner,
+        };
+        env.as_contract(&contract_id, || {
+            EventXContract::transfer_ticket(&env, buyer, transfer_params).unwrap()
+        });
+        
+        env.as_contract(&contract_id, || {
+            assert_eq!(env.storage().instance().get_ttl(), 1000);
+            assert_eq!(
+                env.storage().persistent().get_ttl(&DataKey::Event(event_id)),
+                PERSISTENT_EXTEND_TO + 2000
+            );
+            assert_eq!(
+                env.storage().persistent().get_ttl(&DataKey::Ticket(ticket_id)),
+                PERSISTENT_EXTEND_TO + 2000
+            );
+        });
+    }
+
+    #[test]
+    fn test_bump_event() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let contract_id = env.register_contract(None, EventXContract);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        let buyer = <soroban_sdk::Address as TestAddress>::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&buyer, &10_000);
+        
+        env.as_contract(&contract_id, || {
+            EventXContract::initialize(&env, admin.clone(), token.clone()).unwrap();
+        });
+        
+        let event_params = CreateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 100,
+            ticket_price: 1000,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        
+        let event_id = env.as_contract(&contract_id, || {
+            EventXContract::create_event(&env, admin, event_params).unwrap()
+        });
+        
+        let mut ticket_ids = Vec::new(&env);
+        for _ in 0..3 {
+            let buy_params = BuyTicketParams {
+                event_id,
+                buyer: buyer.clone(),
+                tier: None,
+                seat: TicketSeat::Unassigned,
+                quantity: 1,
+            };
+            let ticket_id = env.as_contract(&contract_id, || {
+                EventXContract::buy_ticket(&env, buyer.clone(), buy_params).unwrap().first().unwrap()
+            });
+            ticket_ids.push_back(ticket_id);
+        }
+        
+        env.ledger().with_mut(|li| {
+            li.sequence_number += PERSISTENT_EXTEND_TO - PERSISTENT_THRESHOLD + 10;
+        });
+        let remaining_ttl = PERSISTENT_THRESHOLD - 10;
+        
+        // İlk çağrı event'i ve ilk iki ticket'ı uzatır
+        let cursor = env.as_contract(&contract_id, || {
+            EventXContract::bump_event(&env, event_id, 0, 2).unwrap()
+        });
+        assert_eq!(cursor, 2);
+        
+        env.as_contract(&contract_id, || {
+            let storage = env.storage().persistent();
+            assert_eq!(storage.get_ttl(&DataKey::Event(event_id)), PERSISTENT_EXTEND_TO);
+            assert_eq!(
+                storage.get_ttl(&DataKey::Ticket(ticket_ids.get(1).unwrap())),
+                PERSISTENT_EXTEND_TO
+            );
+            assert_eq!(
+                storage.get_ttl(&DataKey::Ticket(ticket_ids.get(2).unwrap())),
+                remaining_ttl
+            );
+        });
+        
+        let cursor = env.as_contract(&contract_id, || {
+            EventXContract::bump_event(&env, event_id, cursor, 2).unwrap()
+        });
+        assert_eq!(cursor, 3);
+        
+        env.as_contract(&contract_id, || {
+            assert_eq!(
+                env.storage()
+                    .persistent()
+                    .get_ttl(&DataKey::Ticket(ticket_ids.get(2).unwrap())),
+                PERSISTENT_EXTEND_TO
+            );
+        });
+    }
+
+    #[test]
+    fn test_bump_event_after_archive_window() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let contract_id = env.register_contract(None, EventXContract);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        
+        env.as_contract(&contract_id, || {
+            EventXContract::initialize(&env, admin.clone(), token.clone()).unwrap();
+        });
+        
+        let event_params = CreateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 100,
+            ticket_price: 1000,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        
+        let event_id = env.as_contract(&contract_id, || {
+            EventXContract::create_event(&env, admin, event_params).unwrap()
+        });
+        
+        // Event bitti ve arşiv süresi doldu
+        env.ledger().with_mut(|li| {
+            li.timestamp = 1234567890 + ARCHIVE_AFTER + 1;
+        });
+        
+        let result = env.as_contract(&contract_id, || {
+            EventXContract::bump_event(&env, event_id, 0, 0)
+        });
+        assert_eq!(result, Err(Error::EventArchived));
+    }
+
+    #[test]
+    fn test_event_with_escrow_is_not_archived() {
+        let env = Env::default();
+        let (contract_id, _admin, _buyer, event_id, _ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        
+        // Arşiv süresi dolsa da escrow'da gelir varken kayıtlar canlı tutulur
+        env.ledger().with_mut(|li| {
+            li.timestamp = 1234567890 + ARCHIVE_AFTER + 1;
+        });
+        assert_eq!(client.bump_event(&event_id, &0, &0), 1);
+        
+        // Gelir çekildikten sonra event arşivlenebilir
+        client.withdraw_revenue(&event_id);
+        assert_eq!(
+            client.try_bump_event(&event_id, &0, &0),
+            Err(Ok(Error::EventArchived))
+        );
+    }
+
+    #[test]
+    fn test_event_with_pending_fees_is_not_archived() {
+        let env = Env::default();
+        let (contract_id, admin, buyer, _event_id, _ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        
+        // Ücretin tamamı kesilen event'in escrow'u boş, ücreti event'te bekler
+        client.set_platform_fee(&admin, &FeeConfig { bps: 10_000, flat: 0 });
+        let event_params = CreateEventParams {
+            title: symbol_short!("FeeOnly"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 10,
+            ticket_price: 1000,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        let event_id = client.create_event(&admin, &event_params);
+        let buy_params = BuyTicketParams {
+            event_id,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        client.buy_ticket(&buyer, &buy_params);
+        assert_eq!(client.get_event_escrow(&event_id), 0);
+        
+        env.ledger().with_mut(|li| {
+            li.timestamp = 1234567890 + ARCHIVE_AFTER + 1;
+        });
+        assert_eq!(client.bump_event(&event_id, &0, &0), 1);
+        
+        // Ücret hazineye aktarıldıktan sonra event arşivlenebilir
+        client.withdraw_revenue(&event_id);
+        assert_eq!(client.get_platform_balance(), 1000);
+        assert_eq!(
+            client.try_bump_event(&event_id, &0, &0),
+            Err(Ok(Error::EventArchived))
+        );
+    }
+
+    #[test]
+    fn test_initialize_requires_admin_auth() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, EventXContract);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        
+        // İmza olmadan initialize edilemez
+        assert!(client.try_initialize(&admin, &token).is_err());
+        
+        env.mock_auths(&[MockAuth {
+            address: &admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "initialize",
+                args: (&admin, &token).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        client.initialize(&admin, &token);
+        assert_eq!(client.get_admin(), admin);
+    }
+
+    #[test]
+    fn test_initialize_only_once() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EventXContract);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let attacker = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        
+        assert!(!client.is_initialized());
+        client.initialize(&admin, &token);
+        assert!(client.is_initialized());
+        
+        // İkinci initialize admin'i değiştirememeli
+        assert_eq!(
+            client.try_initialize(&attacker, &token),
+            Err(Ok(Error::AlreadyInitialized))
+        );
+        assert_eq!(client.get_admin(), admin);
+    }
+
+    #[test]
+    fn test_entry_points_require_initialization() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EventXContract);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        
+        let event_params = CreateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 100,
+            ticket_price: 1000,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        let buy_params = BuyTicketParams {
+            event_id: 1,
+            buyer: admin.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        
+        assert_eq!(
+            client.try_create_event(&admin, &event_params),
+            Err(Ok(Error::NotInitialized))
+        );
+        assert_eq!(
+            client.try_buy_ticket(&admin, &buy_params),
+            Err(Ok(Error::NotInitialized))
+        );
+        assert_eq!(client.try_get_admin(), Err(Ok(Error::NotInitialized)));
+        assert_eq!(client.try_get_event(&1), Err(Ok(Error::NotInitialized)));
+        assert_eq!(client.try_event_count(), Err(Ok(Error::NotInitialized)));
+    }
+
+    #[test]
+    fn test_transfer_ticket_requires_owner_auth() {
+        let env = Env::default();
+        let (contract_id, _admin, buyer, _event_id, ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let attacker = <soroban_sdk::Address as TestAddress>::generate(&env);
+        
+        let transfer_params = TransferTicketParams {
+            ticket_id,
+            from: buyer.clone(),
+            to: attacker.clone(),
+        };
+        
+        // Saldırgan sahibin adresini vererek transfer edemez
+        env.mock_auths(&[MockAuth {
+            address: &attacker,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "transfer_ticket",
+                args: (buyer.clone(), transfer_params.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        assert!(client.try_transfer_ticket(&buyer, &transfer_params).is_err());
+        
+        // Kendi adresiyle çağırsa da sahip değildir
+        assert_eq!(
+            client.try_transfer_ticket(&attacker, &transfer_params),
+            Err(Ok(Error::NotOwner))
+        );
+        assert_eq!(client.get_ticket(&ticket_id).owner, buyer);
+        
+        // Sahibin imzasıyla transfer başarılı olur
+        env.mock_auths(&[MockAuth {
+            address: &buyer,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "transfer_ticket",
+                args: (buyer.clone(), transfer_params.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        assert!(client.transfer_ticket(&buyer, &transfer_params));
+        assert_eq!(
+            env.auths(),
+            std::vec![(
+                buyer.clone(),
+                AuthorizedInvocation {
+                    function: AuthorizedFunction::Contract((
+                        contract_id.clone(),
+                        Symbol::new(&env, "transfer_ticket"),
+                        (buyer.clone(), transfer_params.clone()).into_val(&env),
+                    )),
+                    sub_invocations: std::vec![],
+                }
+            )]
+        );
+        assert_eq!(client.get_ticket(&ticket_id).owner, attacker);
+    }
+
+    #[test]
+    fn test_buy_ticket_requires_buyer_auth() {
+        let env = Env::default();
+        let (contract_id, _admin, buyer, event_id, _ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let attacker = <soroban_sdk::Address as TestAddress>::generate(&env);
+        
+        let buy_params = BuyTicketParams {
+            event_id,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        
+        // Saldırgan başkasının bakiyesiyle bilet alamaz
+        env.mock_auths(&[MockAuth {
+            address: &attacker,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "buy_ticket",
+                args: (buyer.clone(), buy_params.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        assert!(client.try_buy_ticket(&buyer, &buy_params).is_err());
+        assert_eq!(client.get_event_ticket_count(&event_id), 1);
+    }
+
+    #[test]
+    fn test_admin_functions_require_admin_auth() {
+        let env = Env::default();
+        let (contract_id, admin, _buyer, event_id, ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let attacker = <soroban_sdk::Address as TestAddress>::generate(&env);
+        
+        let event_params = CreateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 100,
+            ticket_price: 1000,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        
+        // Admin adresi verilse de imza saldırgandan geliyor
+        env.mock_auths(&[
+            MockAuth {
+                address: &attacker,
+                invoke: &MockAuthInvoke {
+                    contract: &contract_id,
+                    fn_name: "create_event",
+                    args: (admin.clone(), event_params.clone()).into_val(&env),
+                    sub_invokes: &[],
+                },
+            },
+            MockAuth {
+                address: &attacker,
+                invoke: &MockAuthInvoke {
+                    contract: &contract_id,
+                    fn_name: "use_ticket",
+                    args: (&admin, ticket_id).into_val(&env),
+                    sub_invokes: &[],
+                },
+            },
+            MockAuth {
+                address: &attacker,
+                invoke: &MockAuthInvoke {
+                    contract: &contract_id,
+                    fn_name: "cancel_event",
+                    args: (&admin, event_id).into_val(&env),
+                    sub_invokes: &[],
+                },
+            },
+            MockAuth {
+                address: &attacker,
+                invoke: &MockAuthInvoke {
+                    contract: &contract_id,
+                    fn_name: "propose_admin",
+                    args: (&admin, &attacker, Option::<u64>::None).into_val(&env),
+                    sub_invokes: &[],
+                },
+            },
+        ]);
+        
+        assert!(client.try_create_event(&admin, &event_params).is_err());
+        assert!(client.try_use_ticket(&admin, &ticket_id).is_err());
+        assert!(client.try_cancel_event(&admin, &event_id, &0).is_err());
+        assert!(client.try_propose_admin(&admin, &attacker, &None).is_err());
+        
+        assert_eq!(client.get_admin(), admin);
+        assert!(!client.get_event(&event_id).is_cancelled);
+        assert!(!client.get_ticket(&ticket_id).is_used);
+        assert_eq!(client.event_count(), 1);
+    }
+
+    #[test]
+    fn test_is_ticket_valid() {
+        let env = Env::default();
+        env.mock_all_auths_allowing_non_root_auth();
+        let contract_id = env.register_contract(None, EventXContract);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        let buyer = <soroban_sdk::Address as TestAddress>::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&buyer, &10_000);
+        
+        env.as_contract(&contract_id, || {
+            EventXContract::initialize(&env, admin.clone(), token.clone()).unwrap();
+        });
+        
+        let event_params = CreateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 100,
+            ticket_price: 1000,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        
+        let event_id = env.as_contract(&contract_id, || {
+            EventXContract::create_event(&env, admin, event_params).unwrap()
+        });
+        
+        let buy_params = BuyTicketParams {
+            event_id,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        
+        let ticket_id = env.as_contract(&contract_id, || {
+            EventXContract::buy_ticket(&env, buyer, buy_params).unwrap().first().unwrap()
+        });
+        
+        let is_valid = env.as_contract(&contract_id, || {
+            EventXContract::is_ticket_valid(&env, ticket_id).unwrap()
+        });
+        assert!(is_valid);
+    }
+
+    #[test]
+    fn test_error_codes() {
+        let env = Env::default();
+        let (contract_id, admin, buyer, event_id, ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let other = <soroban_sdk::Address as TestAddress>::generate(&env);
+        
+        let mut event_params = CreateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 1,
+            ticket_price: -1,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        
+        // Yetki ve parametre hataları
+        assert_eq!(
+            client.try_create_event(&other, &event_params),
+            Err(Ok(Error::Unauthorized))
+        );
+        assert_eq!(
+            client.try_create_event(&admin, &event_params),
+            Err(Ok(Error::InvalidPrice))
+        );
+        let ttl_config = TtlConfig {
+            instance_threshold: 2,
+            instance_extend_to: 1,
+            persistent_threshold: 1,
+            persistent_extend_to: 2,
+            archive_after: 0,
+        };
+        assert_eq!(
+            client.try_set_ttl_config(&admin, &ttl_config),
+            Err(Ok(Error::InvalidTtlConfig))
+        );
+        
+        // Bulunamayan kayıtlar
+        let missing_event = BuyTicketParams {
+            event_id: 99,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        assert_eq!(client.try_get_event(&99), Err(Ok(Error::EventNotFound)));
+        assert_eq!(client.try_get_ticket(&99), Err(Ok(Error::TicketNotFound)));
+        assert_eq!(
+            client.try_buy_ticket(&buyer, &missing_event),
+            Err(Ok(Error::EventNotFound))
+        );
+        assert_eq!(
+            client.try_use_ticket(&admin, &99),
+            Err(Ok(Error::TicketNotFound))
+        );
+        
+        // Başkası adına bilet alınamaz
+        let buy_params = BuyTicketParams {
+            event_id,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        assert_eq!(
+            client.try_buy_ticket(&other, &buy_params),
+            Err(Ok(Error::Unauthorized))
+        );
+        
+        // Biletleri tükenen event
+        event_params.ticket_price = 0;
+        let small_event = client.create_event(&admin, &event_params);
+        let small_params = BuyTicketParams {
+            event_id: small_event,
+            buyer: other.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        client.buy_ticket(&other, &small_params);
+        assert_eq!(
+            client.try_buy_ticket(&other, &small_params),
+            Err(Ok(Error::SoldOut))
+        );
+        
+        // Kullanılmış ticket
+        let transfer_params = TransferTicketParams {
+            ticket_id,
+            from: buyer.clone(),
+            to: other.clone(),
+        };
+        client.use_ticket(&admin, &ticket_id);
+        assert_eq!(
+            client.try_use_ticket(&admin, &ticket_id),
+            Err(Ok(Error::AlreadyUsed))
+        );
+        assert_eq!(
+            client.try_transfer_ticket(&buyer, &transfer_params),
+            Err(Ok(Error::AlreadyUsed))
+        );
+        assert_eq!(
+            client.try_claim_refund(&ticket_id),
+            Err(Ok(Error::AlreadyUsed))
+        );
+        
+        // İptal edilmiş event
+        let refunded_ticket = client.buy_ticket(&buyer, &buy_params).first().unwrap();
+        client.cancel_event(&admin, &event_id, &0);
+        assert_eq!(
+            client.try_cancel_event(&admin, &event_id, &0),
+            Err(Ok(Error::EventCancelled))
+        );
+        assert_eq!(
+            client.try_buy_ticket(&buyer, &buy_params),
+            Err(Ok(Error::EventCancelled))
+        );
+        assert_eq!(
+            client.try_use_ticket(&admin, &refunded_ticket),
+            Err(Ok(Error::AlreadyRefunded))
+        );
+        let transfer_params = TransferTicketParams {
+            ticket_id: refunded_ticket,
+            ..transfer_params
+        };
+        assert_eq!(
+            client.try_transfer_ticket(&buyer, &transfer_params),
+            Err(Ok(Error::AlreadyRefunded))
+        );
+    }
+
+    /// Contract'ın yayınladığı son `count` event'i (topics, data) olarak döndürür
+    fn last_events(env: &Env, contract_id: &Address, count: u32) -> Vec<(Vec<Val>, Val)> {
+        let mut events = Vec::new(env);
+        for (address, topics, data) in env.events().all().iter() {
+            if &address == contract_id {
+                events.push_back((topics, data));
+            }
+        }
+        events.slice(events.len() - count..)
+    }
+
+    #[test]
+    fn test_events_published() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EventXContract);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        let buyer = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let receiver = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let new_admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&buyer, &10_000);
+        
+        client.initialize(&admin, &token);
+        assert_eq!(
+            last_events(&env, &contract_id, 1),
+            vec![
+                &env,
+                (
+                    (symbol_short!("admin"), symbol_short!("changed")).into_val(&env),
+                    AdminChangedPayload {
+                        version: EVENTS_VERSION,
+                        previous_admin: None,
+                        new_admin: admin.clone(),
+                    }
+                    .into_val(&env),
+                ),
+            ]
+        );
+        
+        let event_params = CreateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 100,
+            ticket_price: 1000,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        let event_id = client.create_event(&admin, &event_params);
+        assert_eq!(
+            last_events(&env, &contract_id, 1),
+            vec![
+                &env,
+                (
+                    (symbol_short!("event"), symbol_short!("created"), event_id).into_val(&env),
+                    EventCreatedPayload {
+                        version: EVENTS_VERSION,
+                        organizer: admin.clone(),
+                        title: symbol_short!("TestEvent"),
+                        total_tickets: 100,
+                        ticket_price: 1000,
+                        event_date: 1234567890,
+                    }
+                    .into_val(&env),
+                ),
+            ]
+        );
+        
+        let buy_params = BuyTicketParams {
+            event_id,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        let ticket_id = client.buy_ticket(&buyer, &buy_params).first().unwrap();
+        assert_eq!(
+            last_events(&env, &contract_id, 1),
+            vec![
+                &env,
+                (
+                    (symbol_short!("ticket"), symbol_short!("sold"), ticket_id).into_val(&env),
+                    TicketSoldPayload {
+                        version: EVENTS_VERSION,
+                        event_id,
+                        buyer: buyer.clone(),
+                        price: 1000,
+                        serial: 1,
+                        tier: None,
+                        seat: TicketSeat::Unassigned,
+                    }
+                    .into_val(&env),
+                ),
+            ]
+        );
+        
+        let transfer_params = TransferTicketParams {
+            ticket_id,
+            from: buyer.clone(),
+            to: receiver.clone(),
+        };
+        client.transfer_ticket(&buyer, &transfer_params);
+        assert_eq!(
+            last_events(&env, &contract_id, 1),
+            vec![
+                &env,
+                (
+                    (
+                        symbol_short!("ticket"),
+                        Symbol::new(&env, "transferred"),
+                        ticket_id,
+                    )
+                        .into_val(&env),
+                    TicketTransferredPayload {
+                        version: EVENTS_VERSION,
+                        event_id,
+                        from: buyer.clone(),
+                        to: receiver.clone(),
+                    }
+                    .into_val(&env),
+                ),
+            ]
+        );
+        
+        client.use_ticket(&admin, &ticket_id);
+        assert_eq!(
+            last_events(&env, &contract_id, 1),
+            vec![
+                &env,
+                (
+                    (symbol_short!("ticket"), symbol_short!("used"), ticket_id).into_val(&env),
+                    TicketUsedPayload {
+                        version: EVENTS_VERSION,
+                        event_id,
+                        owner: receiver.clone(),
+                        scanner: admin.clone(),
+                    }
+                    .into_val(&env),
+                ),
+            ]
+        );
+        
+        // İptal, ilk batch'teki refund'larla birlikte yayınlanır
+        let refunded_ticket = client.buy_ticket(&buyer, &buy_params).first().unwrap();
+        client.cancel_event(&admin, &event_id, &0);
+        assert_eq!(
+            last_events(&env, &contract_id, 2),
+            vec![
+                &env,
+                (
+                    (symbol_short!("event"), symbol_short!("cancelled"), event_id)
+                        .into_val(&env),
+                    EventCancelledPayload {
+                        version: EVENTS_VERSION,
+                        tickets_sold: 2,
+                        escrow_balance: 2000,
+                    }
+                    .into_val(&env),
+                ),
+                (
+                    (symbol_short!("ticket"), symbol_short!("refunded"), refunded_ticket)
+                        .into_val(&env),
+                    TicketRefundedPayload {
+                        version: EVENTS_VERSION,
+                        event_id,
+                        owner: buyer.clone(),
+                        amount: 1000,
+                    }
+                    .into_val(&env),
+                ),
+            ]
+        );
+        
+        client.propose_admin(&admin, &new_admin, &None);
+        assert_eq!(
+            last_events(&env, &contract_id, 1),
+            vec![
+                &env,
+                (
+                    (symbol_short!("admin"), symbol_short!("proposed")).into_val(&env),
+                    AdminProposalPayload {
+                        version: EVENTS_VERSION,
+                        admin: admin.clone(),
+                        new_admin: new_admin.clone(),
+                        expires_at: None,
+                    }
+                    .into_val(&env),
+                ),
+            ]
+        );
+        
+        client.accept_admin(&new_admin);
+        assert_eq!(
+            last_events(&env, &contract_id, 1),
+            vec![
+                &env,
+                (
+                    (symbol_short!("admin"), symbol_short!("changed")).into_val(&env),
+                    AdminChangedPayload {
+                        version: EVENTS_VERSION,
+                        previous_admin: Some(admin.clone()),
+                        new_admin,
+                    }
+                    .into_val(&env),
+                ),
+            ]
+        );
+    }
+
+    /// Sayfadaki ticket ID'lerini döndürür
+    fn page_ids(page: &TicketPage) -> std::vec::Vec<u64> {
+        page.tickets.iter().map(|ticket| ticket.id).collect()
+    }
+
+    #[test]
+    fn test_get_user_tickets() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EventXContract);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        let buyer = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let receiver = <soroban_sdk::Address as TestAddress>::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&buyer, &10_000);
+        client.initialize(&admin, &token);
+        
+        let event_params = CreateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 100,
+            ticket_price: 1000,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        let first_event = client.create_event(&admin, &event_params);
+        let second_event = client.create_event(&admin, &event_params);
+        
+        let first_params = BuyTicketParams {
+            event_id: first_event,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        let second_params = BuyTicketParams {
+            event_id: second_event,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        let t1 = client.buy_ticket(&buyer, &first_params).first().unwrap();
+        let t2 = client.buy_ticket(&buyer, &first_params).first().unwrap();
+        let t3 = client.buy_ticket(&buyer, &second_params).first().unwrap();
+        
+        let all = TicketFilter {
+            event_id: None,
+            statuses: Vec::new(&env),
+        };
+        let page = client.get_user_tickets(&buyer, &0, &0, &all);
+        assert_eq!(page_ids(&page), std::vec![t1, t2, t3]);
+        assert_eq!(page.next_cursor, None);
+        assert_eq!(client.get_user_ticket_count(&buyer), 3);
+        
+        // Sayfalama
+        let page = client.get_user_tickets(&buyer, &0, &2, &all);
+        assert_eq!(page_ids(&page), std::vec![t1, t2]);
+        assert_eq!(page.next_cursor, Some(2));
+        let page = client.get_user_tickets(&buyer, &2, &2, &all);
+        assert_eq!(page_ids(&page), std::vec![t3]);
+        assert_eq!(page.next_cursor, None);
+        
+        // Event filtresi
+        let by_event = TicketFilter {
+            event_id: Some(second_event),
+            statuses: Vec::new(&env),
+        };
+        assert_eq!(
+            page_ids(&client.get_user_tickets(&buyer, &0, &0, &by_event)),
+            std::vec![t3]
+        );
+        
+        // Transfer ticket'ı yeni sahibin indeksine taşır, diğer kayıtlar yerinde kalır
+        let transfer_params = TransferTicketParams {
+            ticket_id: t1,
+            from: buyer.clone(),
+            to: receiver.clone(),
+        };
+        client.transfer_ticket(&buyer, &transfer_params);
+        assert_eq!(
+            page_ids(&client.get_user_tickets(&buyer, &0, &0, &all)),
+            std::vec![t2, t3]
+        );
+        assert_eq!(
+            page_ids(&client.get_user_tickets(&receiver, &0, &0, &all)),
+            std::vec![t1]
+        );
+        assert_eq!(client.get_ticket(&t3).owner_slot, 3);
+        assert_eq!(client.get_user_ticket_count(&buyer), 2);
+        assert_eq!(client.get_user_ticket_count(&receiver), 1);
+        
+        // Durum filtresi
+        client.use_ticket(&admin, &t2);
+        client.cancel_event(&admin, &second_event, &0);
+        let by_status = |status: TicketStatus| TicketFilter {
+            event_id: None,
+            statuses: vec![&env, status],
+        };
+        assert_eq!(
+            page_ids(&client.get_user_tickets(&buyer, &0, &0, &by_status(TicketStatus::Used))),
+            std::vec![t2]
+        );
+        assert_eq!(
+            page_ids(&client.get_user_tickets(&buyer, &0, &0, &by_status(TicketStatus::Refunded))),
+            std::vec![t3]
+        );
+        assert_eq!(
+            page_ids(&client.get_user_tickets(&receiver, &0, &0, &by_status(TicketStatus::Active))),
+            std::vec![t1]
+        );
+        assert!(client
+            .get_user_tickets(&buyer, &0, &0, &by_status(TicketStatus::Active))
+            .tickets
+            .is_empty());
+    }
+
+    #[test]
+    fn test_get_event_tickets() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EventXContract);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        let buyer = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let receiver = <soroban_sdk::Address as TestAddress>::generate(&env);
+        token::StellarAssetClient::new(&env, &token).mint(&buyer, &10_000);
+        client.initialize(&admin, &token);
+        
+        let event_params = CreateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 100,
+            ticket_price: 1000,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        let event_id = client.create_event(&admin, &event_params);
+        let other_event = client.create_event(&admin, &event_params);
+        
+        let buy_params = BuyTicketParams {
+            event_id,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        let t1 = client.buy_ticket(&buyer, &buy_params).first().unwrap();
+        let other_params = BuyTicketParams {
+            event_id: other_event,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        client.buy_ticket(&buyer, &other_params);
+        let t2 = client.buy_ticket(&buyer, &buy_params).first().unwrap();
+        let t3 = client.buy_ticket(&buyer, &buy_params).first().unwrap();
+        
+        let transfer_params = TransferTicketParams {
+            ticket_id: t2,
+            from: buyer.clone(),
+            to: receiver.clone(),
+        };
+        client.transfer_ticket(&buyer, &transfer_params);
+        client.use_ticket(&admin, &t1);
+        
+        let page = client.get_event_tickets(&event_id, &0, &2);
+        assert_eq!(page.next_cursor, Some(2));
+        assert_eq!(
+            page.tickets,
+            vec![
+                &env,
+                TicketSummary {
+                    id: t1,
+                    serial: 1,
+                    owner: buyer.clone(),
+                    status: TicketStatus::Used,
+                },
+                TicketSummary {
+                    id: t2,
+                    serial: 2,
+                    owner: receiver.clone(),
+                    status: TicketStatus::Active,
+                },
+            ]
+        );
+        
+        // İptal sonrası kullanılmamış ticket'lar refund edilmiş görünür
+        client.cancel_event(&admin, &event_id, &0);
+        let page = client.get_event_tickets(&event_id, &2, &2);
+        assert_eq!(page.next_cursor, None);
+        assert_eq!(
+            page.tickets,
+            vec![
+                &env,
+                TicketSummary {
+                    id: t3,
+                    serial: 3,
+                    owner: buyer.clone(),
+                    status: TicketStatus::Refunded,
+                },
+            ]
+        );
+        
+        assert_eq!(
+            client.try_get_event_tickets(&99, &0, &0),
+            Err(Ok(Error::EventNotFound))
+        );
+    }
+
+    #[test]
+    fn test_organizer_registry() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EventXContract);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let organizer = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        client.initialize(&admin, &token);
+        
+        let event_params = CreateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 100,
+            ticket_price: 1000,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        
+        // Onaysız adres event oluşturamaz
+        assert!(!client.is_organizer(&organizer));
+        assert_eq!(
+            client.try_create_event(&organizer, &event_params),
+            Err(Ok(Error::Unauthorized))
+        );
+        
+        // Sadece admin organizatör onaylayabilir
+        assert_eq!(
+            client.try_grant_organizer(&organizer, &organizer),
+            Err(Ok(Error::Unauthorized))
+        );
+        client.grant_organizer(&admin, &organizer);
+        assert!(client.is_organizer(&organizer));
+        
+        let event_id = client.create_event(&organizer, &event_params);
+        assert_eq!(client.get_event(&event_id).organizer, organizer);
+        
+        // Event'i admin değil organizatörü yönetir
+        let update_params = UpdateEventParams {
+            title: symbol_short!("NewTitle"),
+            description: symbol_short!("NewDesc"),
+            total_tickets: 50,
+            ticket_price: 1500,
+            event_date: 1234567999,
+        };
+        assert_eq!(
+            client.try_update_event(&admin, &event_id, &update_params),
+            Err(Ok(Error::Unauthorized))
+        );
+        client.update_event(&organizer, &event_id, &update_params);
+        let event = client.get_event(&event_id);
+        assert_eq!(event.title, symbol_short!("NewTitle"));
+        assert_eq!(event.total_tickets, 50);
+        assert_eq!(event.ticket_price, 1500);
+        assert_eq!(event.event_date, 1234567999);
+        
+        assert_eq!(
+            client.try_cancel_event(&admin, &event_id, &0),
+            Err(Ok(Error::Unauthorized))
+        );
+        
+        // Yetkisi kaldırılan organizatör yeni event oluşturamaz ama mevcut event'ini yönetir
+        client.revoke_organizer(&admin, &organizer);
+        assert!(!client.is_organizer(&organizer));
+        assert_eq!(
+            client.try_create_event(&organizer, &event_params),
+            Err(Ok(Error::Unauthorized))
+        );
+        client.cancel_event(&organizer, &event_id, &0);
+        assert!(client.get_event(&event_id).is_cancelled);
+        assert_eq!(
+            client.try_update_event(&organizer, &event_id, &update_params),
+            Err(Ok(Error::EventCancelled))
+        );
+    }
+
+    #[test]
+    fn test_update_event_keeps_sold_tickets() {
+        let env = Env::default();
+        let (contract_id, admin, _buyer, event_id, _ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        
+        let update_params = UpdateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 0,
+            ticket_price: 1000,
+            event_date: 1234567890,
+        };
+        assert_eq!(
+            client.try_update_event(&admin, &event_id, &update_params),
+            Err(Ok(Error::InvalidTicketSupply))
+        );
+        
+        let update_params = UpdateEventParams {
+            total_tickets: 1,
+            ..update_params
+        };
+        client.update_event(&admin, &event_id, &update_params);
+        assert_eq!(client.get_event(&event_id).total_tickets, 1);
+        
+        let update_params = UpdateEventParams {
+            ticket_price: -1,
+            ..update_params
+        };
+        assert_eq!(
+            client.try_update_event(&admin, &event_id, &update_params),
+            Err(Ok(Error::InvalidPrice))
+        );
+    }
+
+    #[test]
+    fn test_update_event_date_after_sales() {
+        let env = Env::default();
+        let (contract_id, admin, _buyer, event_id, _ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        
+        // Satış varken tarih öne çekilirse gelir erken çekilebilirdi
+        let update_params = UpdateEventParams {
+            title: symbol_short!("TestEvent"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 100,
+            ticket_price: 1000,
+            event_date: 1,
+        };
+        assert_eq!(
+            client.try_update_event(&admin, &event_id, &update_params),
+            Err(Ok(Error::InvalidEventDate))
+        );
+        env.ledger().with_mut(|li| {
+            li.timestamp = 1 + client.get_hold_period() + 1;
+        });
+        assert_eq!(
+            client.try_withdraw_revenue(&event_id),
+            Err(Ok(Error::RevenueLocked))
+        );
+        
+        // İleri almak serbesttir ve bekleme süresi yeni tarihten başlar
+        let update_params = UpdateEventParams {
+            event_date: 1234567900,
+            ..update_params
+        };
+        client.update_event(&admin, &event_id, &update_params);
+        assert_eq!(client.get_event(&event_id).event_date, 1234567900);
+        env.ledger().with_mut(|li| {
+            li.timestamp = 1234567891 + client.get_hold_period();
+        });
+        assert_eq!(
+            client.try_withdraw_revenue(&event_id),
+            Err(Ok(Error::RevenueLocked))
+        );
+        
+        // Satış yoksa tarih öne çekilebilir
+        let event_params = CreateEventParams {
+            title: symbol_short!("Other"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 100,
+            ticket_price: 1000,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        let other_event = client.create_event(&admin, &event_params);
+        let update_params = UpdateEventParams {
+            event_date: 1234567000,
+            ..update_params
+        };
+        client.update_event(&admin, &other_event, &update_params);
+        assert_eq!(client.get_event(&other_event).event_date, 1234567000);
+    }
+
+    #[test]
+    fn test_scanner_check_in() {
+        let env = Env::default();
+        let (contract_id, admin, buyer, event_id, ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let scanner = <soroban_sdk::Address as TestAddress>::generate(&env);
+        
+        // İkinci event ve ticket'ı
+        let event_params = CreateEventParams {
+            title: symbol_short!("Other"),
+            description: symbol_short!("TestDesc"),
+            total_tickets: 100,
+            ticket_price: 1000,
+            event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        let other_event = client.create_event(&admin, &event_params);
+        let other_params = BuyTicketParams {
+            event_id: other_event,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        let other_ticket = client.buy_ticket(&buyer, &other_params).first().unwrap();
+        
+        // Atanmamış görevli check-in yapamaz
+        assert_eq!(
+            client.try_use_ticket(&scanner, &ticket_id),
+            Err(Ok(Error::Unauthorized))
+        );
+        
+        // Sadece organizatör görevli atayabilir
+        assert_eq!(
+            client.try_add_scanner(&scanner, &event_id, &scanner),
+            Err(Ok(Error::Unauthorized))
+        );
+        client.add_scanner(&admin, &event_id, &scanner);
+        assert!(client.is_scanner(&event_id, &scanner));
+        assert!(!client.is_scanner(&other_event, &scanner));
+        
+        // Görevli yalnızca atandığı event'te check-in yapabilir
+        assert_eq!(
+            client.try_use_ticket(&scanner, &other_ticket),
+            Err(Ok(Error::Unauthorized))
+        );
+        client.use_ticket(&scanner, &ticket_id);
+        assert!(client.get_ticket(&ticket_id).is_used);
+        assert_eq!(
+            last_events(&env, &contract_id, 1),
+            vec![
+                &env,
+                (
+                    (symbol_short!("ticket"), symbol_short!("used"), ticket_id).into_val(&env),
+                    TicketUsedPayload {
+                        version: EVENTS_VERSION,
+                        event_id,
+                        owner: buyer.clone(),
+                        scanner: scanner.clone(),
+                    }
+                    .into_val(&env),
+                ),
+            ]
+        );
+        
+        // Görevliliği kaldırılan adres check-in yapamaz
+        client.remove_scanner(&admin, &event_id, &scanner);
+        assert!(!client.is_scanner(&event_id, &scanner));
+        let next_ticket = client.buy_ticket(
+            &buyer,
+            &BuyTicketParams {
+                event_id,
+                buyer: buyer.clone(),
+                tier: None,
+                seat: TicketSeat::Unassigned,
+                quantity: 1,
+            },
+        ).first().unwrap();
+        assert_eq!(
+            client.try_use_ticket(&scanner, &next_ticket),
+            Err(Ok(Error::Unauthorized))
+        );
+    }
+
+    #[test]
+    fn test_admin_handover() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register_contract(None, EventXContract);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let new_admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let other = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token = create_token(&env, &admin);
+        client.initialize(&admin, &token);
+        
+        assert_eq!(client.try_accept_admin(&new_admin), Err(Ok(Error::NoPendingAdmin)));
+        assert_eq!(
+            client.try_cancel_admin_proposal(&admin),
+            Err(Ok(Error::NoPendingAdmin))
+        );
+        
+        // Öneri admin'i hemen değiştirmez
+        client.propose_admin(&admin, &new_admin, &None);
+        assert_eq!(client.get_admin(), admin);
+        assert_eq!(
+            client.get_pending_admin(),
+            Some(PendingAdmin {
+                new_admin: new_admin.clone(),
+                expires_at: None,
+            })
+        );
+        
+        // Sadece önerilen adres kabul edebilir
+        assert_eq!(client.try_accept_admin(&other), Err(Ok(Error::Unauthorized)));
+        
+        // İptal edilen öneri kabul edilemez
+        client.cancel_admin_proposal(&admin);
+        assert_eq!(client.get_pending_admin(), None);
+        assert_eq!(client.try_accept_admin(&new_admin), Err(Ok(Error::NoPendingAdmin)));
+        
+        // Süresi dolan öneri kabul edilemez
+        client.propose_admin(&admin, &new_admin, &Some(1000));
+        env.ledger().with_mut(|li| {
+            li.timestamp = 1001;
+        });
+        assert_eq!(client.try_accept_admin(&new_admin), Err(Ok(Error::ProposalExpired)));
+        assert_eq!(client.get_admin(), admin);
+        
+        // Süresi içinde kabul edilen öneri admin'i değiştirir
+        client.propose_admin(&admin, &new_admin, &Some(2000));
+        client.accept_admin(&new_admin);
+        assert_eq!(client.get_admin(), new_admin);
+        assert_eq!(client.get_pending_admin(), None);
+        assert_eq!(
+            client.try_propose_admin(&admin, &other, &None),
+            Err(Ok(Error::Unauthorized))
+        );
+    }
+
+    #[test]
+    fn test_accept_admin_requires_new_admin_auth() {
+        let env = Env::default();
+        let (contract_id, admin, _buyer, _event_id, _ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let new_admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        
+        client.propose_admin(&admin, &new_admin, &None);
+        
+        // Yeni admin'in imzası olmadan devir tamamlanmaz
+        env.mock_auths(&[MockAuth {
+            address: &admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "accept_admin",
+                args: (&new_admin,).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        assert!(client.try_accept_admin(&new_admin).is_err());
+        assert_eq!(client.get_admin(), admin);
+    }
+
+    #[test]
+    fn test_council_actions() {
+        let env = Env::default();
+        let (contract_id, admin, _buyer, event_id, ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let m1 = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let m2 = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let m3 = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let outsider = <soroban_sdk::Address as TestAddress>::generate(&env);
+        
+        // Geçersiz konsey kurulamaz
+        let members = vec![&env, m1.clone(), m2.clone(), m3.clone()];
+        assert_eq!(
+            client.try_set_council(&admin, &members, &4),
+            Err(Ok(Error::InvalidCouncil))
+        );
+        assert_eq!(
+            client.try_set_council(&admin, &vec![&env, m1.clone(), m1.clone()], &1),
+            Err(Ok(Error::InvalidCouncil))
+        );
+        
+        // 2/3 konsey kurulduktan sonra admin tek başına devir yapamaz
+        client.set_council(&admin, &members, &2);
+        assert_eq!(
+            client.try_propose_admin(&admin, &outsider, &None),
+            Err(Ok(Error::CouncilRequired))
+        );
+        assert_eq!(
+            client.try_set_council(&admin, &members, &1),
+            Err(Ok(Error::CouncilRequired))
+        );
+        
+        // Sadece üyeler öneri yapabilir
+        let cancel = CouncilAction::CancelEvent(event_id);
+        assert_eq!(
+            client.try_propose_council_action(&outsider, &cancel, &1000),
+            Err(Ok(Error::NotCouncilMember))
+        );
+        
+        // Tek onay eşiğe ulaşmaz
+        let proposal_id = client.propose_council_action(&m1, &cancel, &1000);
+        assert!(!client.get_event(&event_id).is_cancelled);
+        assert_eq!(
+            client.try_approve_council_action(&m1, &proposal_id),
+            Err(Ok(Error::AlreadyApproved))
+        );
+        assert_eq!(
+            client.try_approve_council_action(&outsider, &proposal_id),
+            Err(Ok(Error::NotCouncilMember))
+        );
+        
+        // İkinci onayla öneri uygulanır
+        assert!(client.approve_council_action(&m2, &proposal_id));
+        assert!(client.get_event(&event_id).is_cancelled);
+        assert!(client.get_ticket(&ticket_id).is_refunded);
+        assert!(client.get_council_proposal(&proposal_id).executed);
+        assert_eq!(
+            client.try_approve_council_action(&m3, &proposal_id),
+            Err(Ok(Error::ProposalExecuted))
+        );
+        
+        // Süresi dolan öneri onaylanamaz
+        let set_admin = CouncilAction::SetAdmin(outsider.clone());
+        let expired_id = client.propose_council_action(&m1, &set_admin, &1000);
+        env.ledger().with_mut(|li| {
+            li.timestamp = 1001;
+        });
+        assert_eq!(
+            client.try_approve_council_action(&m2, &expired_id),
+            Err(Ok(Error::ProposalExpired))
+        );
+        assert_eq!(client.get_admin(), admin);
+        
+        // Admin değişikliği
+        let admin_id = client.propose_council_action(&m1, &set_admin, &2000);
+        client.approve_council_action(&m3, &admin_id);
+        assert_eq!(client.get_admin(), outsider);
+    }
+
+    #[test]
+    fn test_council_rotation() {
+        let env = Env::default();
+        let (contract_id, admin, _buyer, event_id, _ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let m1 = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let m2 = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let m3 = <soroban_sdk::Address as TestAddress>::generate(&env);
+        
+        client.set_council(&admin, &vec![&env, m1.clone(), m2.clone()], &2);
+        
+        // Rotasyondan önce açılan öneri
+        let cancel = CouncilAction::CancelEvent(event_id);
+        let stale_id = client.propose_council_action(&m1, &cancel, &1000);
+        
+        // m2 çıkarılır, m3 eklenir, eşik 1'e iner
+        let rotate = CouncilAction::SetCouncil(vec![&env, m1.clone(), m3.clone()], 1);
+        let rotate_id = client.propose_council_action(&m1, &rotate, &1000);
+        assert!(client.approve_council_action(&m2, &rotate_id));
+        assert_eq!(
+            client.get_council(),
+            Some(Council {
+                members: vec![&env, m1.clone(), m3.clone()],
+                threshold: 1,
+                epoch: 1,
+            })
+        );
+        
+        // Çıkarılan üye onay veremez, eski dönemin önerileri geçersizdir
+        assert_eq!(
+            client.try_approve_council_action(&m2, &stale_id),
+            Err(Ok(Error::NotCouncilMember))
+        );
+        assert_eq!(
+            client.try_approve_council_action(&m3, &stale_id),
+            Err(Ok(Error::ProposalExpired))
+        );
+        
+        // Eşik 1 olduğunda öneri hemen uygulanır
+        client.propose_council_action(&m3, &cancel, &1000);
+        assert!(client.get_event(&event_id).is_cancelled);
+    }
+
+    #[test]
+    fn test_council_approval_requires_member_auth() {
+        let env = Env::default();
+        let (contract_id, admin, _buyer, event_id, _ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let m1 = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let m2 = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let attacker = <soroban_sdk::Address as TestAddress>::generate(&env);
+        
+        client.set_council(&admin, &vec![&env, m1.clone(), m2.clone()], &2);
+        let cancel = CouncilAction::CancelEvent(event_id);
+        let proposal_id = client.propose_council_action(&m1, &cancel, &1000);
+        
+        // m2 adına saldırgan imzası
+        env.mock_auths(&[MockAuth {
+            address: &attacker,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "approve_council_action",
+                args: (&m2, proposal_id).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        assert!(client.try_approve_council_action(&m2, &proposal_id).is_err());
+        assert!(!client.get_event(&event_id).is_cancelled);
+    }
+
+    #[test]
+    fn test_pause() {
+        let env = Env::default();
+        let (contract_id, admin, buyer, event_id, ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let guardian = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let receiver = <soroban_sdk::Address as TestAddress>::generate(&env);
+        
+        let buy_params = BuyTicketParams {
+            event_id,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        let transfer_params = TransferTicketParams {
+            ticket_id,
+            from: buyer.clone(),
+            to: receiver.clone(),
+        };
+        
+        // Guardian atanmadan önce durduramaz
+        assert_eq!(client.try_pause(&guardian), Err(Ok(Error::Unauthorized)));
+        client.set_guardian(&admin, &Some(guardian.clone()));
+        assert_eq!(client.get_guardian(), Some(guardian.clone()));
+        
+        // Guardian durdurur, yalnızca admin yeniden başlatır
+        client.pause(&guardian);
+        assert!(client.is_paused());
+        assert_eq!(
+            last_events(&env, &contract_id, 1),
+            vec![
+                &env,
+                (
+                    (symbol_short!("contract"), symbol_short!("paused")).into_val(&env),
+                    PausePayload {
+                        version: EVENTS_VERSION,
+                        by: guardian.clone(),
+                    }
+                    .into_val(&env),
+                ),
+            ]
+        );
+        assert_eq!(
+            client.try_buy_ticket(&buyer, &buy_params),
+            Err(Ok(Error::ContractPaused))
+        );
+        assert_eq!(
+            client.try_transfer_ticket(&buyer, &transfer_params),
+            Err(Ok(Error::ContractPaused))
+        );
+        assert_eq!(
+            client.try_use_ticket(&admin, &ticket_id),
+            Err(Ok(Error::ContractPaused))
+        );
+        
+        // Sorgular çalışmaya devam eder
+        assert_eq!(client.get_ticket(&ticket_id).owner, buyer);
+        
+        assert_eq!(client.try_unpause(&guardian), Err(Ok(Error::Unauthorized)));
+        client.unpause(&admin);
+        assert!(!client.is_paused());
+        client.transfer_ticket(&buyer, &transfer_params);
+        
+        // Event bazında durdurma
+        client.pause_event(&guardian, &event_id);
+        let event = client.get_event(&event_id);
+        assert!(event.is_paused);
+        assert!(event.is_active);
+        assert!(!client.is_ticket_valid(&ticket_id));
+        assert_eq!(
+            client.try_buy_ticket(&buyer, &buy_params),
+            Err(Ok(Error::EventPaused))
+        );
+        let transfer_back = TransferTicketParams {
+            ticket_id,
+            from: receiver.clone(),
+            to: buyer.clone(),
+        };
+        assert_eq!(
+            client.try_transfer_ticket(&receiver, &transfer_back),
+            Err(Ok(Error::EventPaused))
+        );
+        assert_eq!(
+            client.try_use_ticket(&admin, &ticket_id),
+            Err(Ok(Error::EventPaused))
+        );
+        let paused = EventFilter {
+            statuses: vec![&env, EventStatus::Paused],
+            organizer: None,
+            date_after: None,
+            date_before: None,
+        };
+        assert_eq!(client.list_events(&0, &0, &paused).events.len(), 1);
+        assert_eq!(
+            client.try_unpause_event(&guardian, &event_id),
+            Err(Ok(Error::Unauthorized))
+        );
+        client.unpause_event(&admin, &event_id);
+        assert!(!client.get_event(&event_id).is_paused);
+        assert!(client.list_events(&0, &0, &paused).events.is_empty());
+        client.buy_ticket(&buyer, &buy_params);
+        
+        // Guardian kaldırılınca yetkisi biter
+        client.set_guardian(&admin, &None);
+        assert_eq!(client.try_pause(&guardian), Err(Ok(Error::Unauthorized)));
+    }
+
+    #[test]
+    fn test_refunds_allowed_while_paused() {
+        let env = Env::default();
+        let (contract_id, admin, buyer, event_id, ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let token = client.get_token();
+        let token_client = token::Client::new(&env, &token);
+        
+        // Refund'ları batch'lere bölmek için ikinci ticket
+        let buy_params = BuyTicketParams {
+            event_id,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        let second_ticket = client.buy_ticket(&buyer, &buy_params).first().unwrap();
+        
+        client.pause(&admin);
+        client.pause_event(&admin, &event_id);
+        
+        // Durdurulmuş event iptal edilip refund edilebilir
+        client.cancel_event(&admin, &event_id, &0);
+        assert!(client.get_ticket(&ticket_id).is_refunded);
+        assert!(client.get_ticket(&second_ticket).is_refunded);
+        assert_eq!(token_client.balance(&buyer), 10_000);
+        assert_eq!(
+            client.try_unpause_event(&admin, &event_id),
+            Err(Ok(Error::EventCancelled))
+        );
+    }
+
+    #[test]
+    fn test_withdraw_revenue() {
+        let env = Env::default();
+        let (contract_id, admin, buyer, event_id, _ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let token_client = token::Client::new(&env, &client.get_token());
+        let buy_params = BuyTicketParams {
+            event_id,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        client.buy_ticket(&buyer, &buy_params);
+        
+        // Event tarihi ve bekleme süresi geçmeden gelir çekilemez
+        let unlock_at = 1234567890 + client.get_hold_period();
+        assert_eq!(
+            client.try_withdraw_revenue(&event_id),
+            Err(Ok(Error::RevenueLocked))
+        );
+        env.ledger().with_mut(|li| {
+            li.timestamp = unlock_at;
+        });
+        assert_eq!(
+            client.try_withdraw_revenue(&event_id),
+            Err(Ok(Error::RevenueLocked))
+        );
+        
+        env.ledger().with_mut(|li| {
+            li.timestamp = unlock_at + 1;
+        });
+        assert_eq!(client.withdraw_revenue(&event_id), 2000);
+        assert_eq!(token_client.balance(&admin), 2000);
+        assert_eq!(
+            last_events(&env, &contract_id, 1),
+            vec![
+                &env,
+                (
+                    (symbol_short!("event"), symbol_short!("payout"), event_id).into_val(&env),
+                    RevenueWithdrawnPayload {
+                        version: EVENTS_VERSION,
+                        organizer: admin.clone(),
+                        amount: 2000,
+                        total_withdrawn: 2000,
+                    }
+                    .into_val(&env),
+                ),
+            ]
+        );
+        
+        let event = client.get_event(&event_id);
+        assert_eq!(event.escrow_balance, 0);
+        assert_eq!(event.revenue_withdrawn, 2000);
+        assert_eq!(
+            client.try_withdraw_revenue(&event_id),
+            Err(Ok(Error::NothingToWithdraw))
+        );
+        
+        // Event tarihinden sonra satış yapılamaz
+        assert_eq!(
+            client.try_buy_ticket(&buyer, &buy_params),
+            Err(Ok(Error::SalesClosed))
+        );
+        assert_eq!(client.get_event(&event_id).revenue_withdrawn, 2000);
+        
+        // Geliri ödenmiş event iptal edilemez
+        assert_eq!(
+            client.try_cancel_event(&admin, &event_id, &0),
+            Err(Ok(Error::RevenueWithdrawn))
+        );
+    }
+
+    #[test]
+    fn test_withdraw_revenue_cancelled_event() {
+        let env = Env::default();
+        let (contract_id, admin, _buyer, event_id, _ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        
+        client.set_hold_period(&admin, &0);
+        assert_eq!(client.get_hold_period(), 0);
+        client.cancel_event(&admin, &event_id, &0);
+        
+        env.ledger().with_mut(|li| {
+            li.timestamp = 1234567891;
+        });
+        assert_eq!(
+            client.try_withdraw_revenue(&event_id),
+            Err(Ok(Error::EventCancelled))
+        );
+    }
+
+    #[test]
+    fn test_cancelled_event_releases_used_ticket_revenue() {
+        let env = Env::default();
+        let (contract_id, admin, buyer, event_id, first_ticket) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let token_client = token::Client::new(&env, &client.get_token());
+        let treasury = <soroban_sdk::Address as TestAddress>::generate(&env);
+        
+        // %10 ücretli ikinci bilet kullanıldıktan sonra event iptal edilir
+        client.set_platform_fee(&admin, &FeeConfig { bps: 1000, flat: 0 });
+        let buy_params = BuyTicketParams {
+            event_id,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        let used_ticket = client.buy_ticket(&buyer, &buy_params).first().unwrap();
+        client.use_ticket(&admin, &used_ticket);
+        client.cancel_event(&admin, &event_id, &0);
+        
+        // Kullanılmamış bilet iade edilir, kullanılmış biletin geliri organizatöre ayrılır
+        assert!(client.get_ticket(&first_ticket).is_refunded);
+        assert!(!client.get_ticket(&used_ticket).is_refunded);
+        assert_eq!(client.get_event_escrow(&event_id), 0);
+        assert_eq!(client.get_event(&event_id).used_revenue, 900);
+        assert_eq!(client.get_platform_balance(), 100);
+        
+        // Ayrılan gelir bekleme süresinden sonra çekilir
+        assert_eq!(
+            client.try_withdraw_revenue(&event_id),
+            Err(Ok(Error::RevenueLocked))
+        );
+        env.ledger().with_mut(|li| {
+            li.timestamp = 1234567891 + client.get_hold_period();
+        });
+        assert_eq!(client.withdraw_revenue(&event_id), 900);
+        assert_eq!(token_client.balance(&admin), 900);
+        assert_eq!(
+            client.try_withdraw_revenue(&event_id),
+            Err(Ok(Error::EventCancelled))
+        );
+        assert_eq!(client.withdraw_platform_fees(&admin, &treasury), 100);
+        assert_eq!(token_client.balance(&contract_id), 0);
+    }
+
+    #[test]
+    fn test_withdraw_revenue_requires_organizer_auth() {
+        let env = Env::default();
+        let (contract_id, admin, _buyer, event_id, _ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let attacker = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let token_client = token::Client::new(&env, &client.get_token());
+        
+        client.set_hold_period(&admin, &0);
+        env.ledger().with_mut(|li| {
+            li.timestamp = 1234567891;
+        });
+        
+        env.mock_auths(&[MockAuth {
+            address: &attacker,
+            invoke: &MockAuthInvoke {
+                contract: &contract_id,
+                fn_name: "withdraw_revenue",
+                args: (event_id,).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]);
+        assert!(client.try_withdraw_revenue(&event_id).is_err());
+        assert_eq!(token_client.balance(&admin), 0);
+        assert_eq!(client.get_event_escrow(&event_id), 1000);
+    }
+
+    #[test]
+    fn test_platform_fees() {
+        let env = Env::default();
+        let (contract_id, admin, buyer, event_id, _ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let token_client = token::Client::new(&env, &client.get_token());
+        let organizer = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let treasury = <soroban_sdk::Address as TestAddress>::generate(&env);
+        
+        // Varsayılan ücret yok
+        assert_eq!(client.get_platform_fee(), FeeConfig { bps: 0, flat: 0 });
+        assert_eq!(client.get_event_fees(&event_id), 0);
+        assert_eq!(
+            client.try_set_platform_fee(&admin, &FeeConfig { bps: 10_001, flat: 0 }),
+            Err(Ok(Error::InvalidFee))
+        );
+        assert_eq!(
+            client.try_set_platform_fee(&admin, &FeeConfig { bps: 0, flat: -1 }),
+            Err(Ok(Error::InvalidFee))
+        );
+        
+        // %10 + 50 ücret: 1000'lik biletten 150 kesilir
+        client.set_platform_fee(&admin, &FeeConfig { bps: 1000, flat: 50 });
+        let buy_params = BuyTicketParams {
+            event_id,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        let ticket_id = client.buy_ticket(&buyer, &buy_params).first().unwrap();
+        assert_eq!(client.get_ticket(&ticket_id).fee_paid, 150);
+        assert_eq!(client.get_event_fees(&event_id), 150);
+        assert_eq!(client.get_event_escrow(&event_id), 1850);
+        assert_eq!(client.get_platform_balance(), 0);
+        
+        // Organizatöre özel ücret varsayılanın yerine geçer
+        client.grant_organizer(&admin, &organizer);
+        client.set_organizer_fee(&admin, &organizer, &Some(FeeConfig { bps: 500, flat: 0 }));
+        assert_eq!(
+            last_events(&env, &contract_id, 1),
+            vec![
+                &env,
+                (
+                    (symbol_short!("fee"), symbol_short!("changed")).into_val(&env),
+                    FeeChangedPayload {
+                        version: EVENTS_VERSION,
+                        organizer: Some(organizer.clone()),
+                        fee: FeeConfig { bps: 500, flat: 0 },
+                    }
+                    .into_val(&env),
+                ),
+            ]
+        );
+        let organizer_event = client.create_event(
+            &organizer,
+            &CreateEventParams {
+                title: symbol_short!("OrgEvent"),
+                description: symbol_short!("OrgDesc"),
+                total_tickets: 10,
+                ticket_price: 1000,
+                event_date: 1234567890,
+                tiers: Vec::new(&env),
+            },
+        );
+        let organizer_params = BuyTicketParams {
+            event_id: organizer_event,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        client.buy_ticket(&buyer, &organizer_params);
+        assert_eq!(client.get_event_fees(&organizer_event), 50);
+        
+        // Özel ücret kaldırılınca varsayılan ücret geçerli olur
+        client.set_organizer_fee(&admin, &organizer, &None);
+        assert_eq!(client.get_organizer_fee(&organizer), None);
+        client.buy_ticket(&buyer, &organizer_params);
+        assert_eq!(client.get_event_fees(&organizer_event), 200);
+        
+        // Ücretler gelir çekilene kadar event'te bekler
+        assert_eq!(
+            client.try_withdraw_platform_fees(&admin, &treasury),
+            Err(Ok(Error::NothingToWithdraw))
+        );
+        env.ledger().with_mut(|li| {
+            li.timestamp = 1234567891 + client.get_hold_period();
+        });
+        assert_eq!(client.withdraw_revenue(&event_id), 1850);
+        assert_eq!(client.get_platform_balance(), 150);
+        assert_eq!(client.withdraw_revenue(&organizer_event), 1800);
+        assert_eq!(client.get_platform_balance(), 350);
+        
+        // Hazine sadece admin tarafından boşaltılabilir
+        assert_eq!(
+            client.try_withdraw_platform_fees(&organizer, &treasury),
+            Err(Ok(Error::Unauthorized))
+        );
+        assert_eq!(client.withdraw_platform_fees(&admin, &treasury), 350);
+        assert_eq!(token_client.balance(&treasury), 350);
+        assert_eq!(client.get_platform_balance(), 0);
+        assert_eq!(
+            last_events(&env, &contract_id, 1),
+            vec![
+                &env,
+                (
+                    (symbol_short!("fee"), symbol_short!("withdrawn")).into_val(&env),
+                    FeesWithdrawnPayload {
+                        version: EVENTS_VERSION,
+                        to: treasury.clone(),
+                        amount: 350,
+                    }
+                    .into_val(&env),
+                ),
+            ]
+        );
+        assert_eq!(
+            client.try_withdraw_platform_fees(&admin, &treasury),
+            Err(Ok(Error::NothingToWithdraw))
+        );
+        
+        // Event başına kesilen ücretler çekimden sonra da raporlanır
+        assert_eq!(client.get_event_fees(&event_id), 150);
+    }
 
     #[test]
-    fn test_initialize() {
+    fn test_refund_returns_platform_fee() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, EventXContract);
-        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let (contract_id, admin, buyer, event_id, first_ticket) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let token_client = token::Client::new(&env, &client.get_token());
         
-        env.as_contract(&contract_id, || {
-            EventXContract::initialize(&env, admin.clone());
-        });
+        client.set_platform_fee(&admin, &FeeConfig { bps: 250, flat: 0 });
+        let buy_params = BuyTicketParams {
+            event_id,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        let ticket_id = client.buy_ticket(&buyer, &buy_params).first().unwrap();
+        assert_eq!(token_client.balance(&buyer), 8000);
         
-        let stored_admin = env.as_contract(&contract_id, || {
-            EventXContract::get_admin(&env)
-        });
-        assert_eq!(stored_admin, admin);
+        // İptalde platform ücreti dahil ödenen tutarın tamamı iade edilir
+        assert_eq!(client.get_event_fees(&event_id), 25);
+        client.cancel_event(&admin, &event_id, &0);
+        assert!(client.get_ticket(&first_ticket).is_refunded);
+        assert!(client.get_ticket(&ticket_id).is_refunded);
+        assert_eq!(token_client.balance(&buyer), 10000);
+        assert_eq!(client.get_event_escrow(&event_id), 0);
+        assert_eq!(client.get_event_fees(&event_id), 0);
+        assert_eq!(client.get_platform_balance(), 0);
     }
 
     #[test]
-    fn test_create_event() {
+    fn test_platform_fee_overflow() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, EventXContract);
-        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let (contract_id, admin, buyer, _event_id, _ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
         
-        env.as_contract(&contract_id, || {
-            EventXContract::initialize(&env, admin.clone());
-        });
+        // Organizatöre özel ücret de negatif sabit ücret alamaz
+        assert_eq!(
+            client.try_set_organizer_fee(&admin, &admin, &Some(FeeConfig { bps: 0, flat: -1 })),
+            Err(Ok(Error::InvalidFee))
+        );
         
-        let params = CreateEventParams {
-            title: symbol_short!("TestEvent"),
+        // Oran hesabında taşan fiyatla satış yapılamaz
+        client.set_platform_fee(&admin, &FeeConfig { bps: 100, flat: i128::MAX });
+        let event_params = CreateEventParams {
+            title: symbol_short!("Huge"),
             description: symbol_short!("TestDesc"),
-            total_tickets: 100,
-            ticket_price: 1000,
+            total_tickets: 10,
+            ticket_price: i128::MAX / 10,
             event_date: 1234567890,
+            tiers: Vec::new(&env),
+        };
+        let event_id = client.create_event(&admin, &event_params);
+        let buy_params = BuyTicketParams {
+            event_id,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
+        };
+        assert_eq!(
+            client.try_buy_ticket(&buyer, &buy_params),
+            Err(Ok(Error::InvalidPrice))
+        );
+        assert_eq!(client.get_event(&event_id).tickets_sold, 0);
+    }
+
+    #[test]
+    fn test_settle_fees_without_organizer() {
+        let env = Env::default();
+        let (contract_id, admin, buyer, event_id, _ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let token_client = token::Client::new(&env, &client.get_token());
+        let treasury = <soroban_sdk::Address as TestAddress>::generate(&env);
+        
+        client.set_platform_fee(&admin, &FeeConfig { bps: 1000, flat: 0 });
+        let buy_params = BuyTicketParams {
+            event_id,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
         };
+        client.buy_ticket(&buyer, &buy_params);
         
-        let event_id = env.as_contract(&contract_id, || {
-            EventXContract::create_event(&env, admin, params)
+        // Bekleme süresi dolmadan ücret hazineye aktarılamaz
+        assert_eq!(
+            client.try_settle_fees(&event_id),
+            Err(Ok(Error::RevenueLocked))
+        );
+        
+        // Organizatör gelirini çekmese de ücret herkes tarafından aktarılabilir
+        env.ledger().with_mut(|li| {
+            li.timestamp = 1234567891 + client.get_hold_period();
         });
+        env.mock_auths(&[]);
+        assert_eq!(client.settle_fees(&event_id), 100);
+        assert_eq!(
+            last_events(&env, &contract_id, 1),
+            vec![
+                &env,
+                (
+                    (symbol_short!("fee"), symbol_short!("settled"), event_id).into_val(&env),
+                    FeesSettledPayload {
+                        version: EVENTS_VERSION,
+                        amount: 100,
+                    }
+                    .into_val(&env),
+                ),
+            ]
+        );
+        assert_eq!(client.get_platform_balance(), 100);
+        assert_eq!(client.get_event_escrow(&event_id), 1900);
+        assert_eq!(
+            client.try_settle_fees(&event_id),
+            Err(Ok(Error::NothingToWithdraw))
+        );
         
-        let event = env.as_contract(&contract_id, || {
-            EventXContract::get_event(&env, event_id)
-        }).unwrap();
+        env.mock_all_auths();
+        assert_eq!(client.withdraw_platform_fees(&admin, &treasury), 100);
+        assert_eq!(token_client.balance(&treasury), 100);
         
-        assert_eq!(event.title, symbol_short!("TestEvent"));
-        assert_eq!(event.total_tickets, 100);
-        assert_eq!(event.tickets_sold, 0);
-        assert_eq!(event.ticket_price, 1000);
-        assert!(event.is_active);
-        assert!(!event.is_cancelled);
+        // Organizatör daha sonra yalnızca kendi gelirini çeker
+        assert_eq!(client.withdraw_revenue(&event_id), 1900);
+        assert_eq!(client.get_platform_balance(), 0);
     }
 
     #[test]
-    fn test_buy_ticket() {
+    fn test_fee_settlement_blocks_cancel() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, EventXContract);
-        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
-        let buyer = <soroban_sdk::Address as TestAddress>::generate(&env);
-        
-        env.as_contract(&contract_id, || {
-            EventXContract::initialize(&env, admin.clone());
-        });
+        let (contract_id, admin, buyer, _event_id, _ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
         
+        // Ücretin tamamı kesildiğinde organizatöre ödenecek gelir kalmaz
+        client.set_platform_fee(&admin, &FeeConfig { bps: 10_000, flat: 0 });
         let event_params = CreateEventParams {
-            title: symbol_short!("TestEvent"),
+            title: symbol_short!("FeeOnly"),
             description: symbol_short!("TestDesc"),
-            total_tickets: 100,
+            total_tickets: 10,
             ticket_price: 1000,
             event_date: 1234567890,
+            tiers: Vec::new(&env),
         };
-        
-        let event_id = env.as_contract(&contract_id, || {
-            EventXContract::create_event(&env, admin, event_params)
-        });
-        
+        let event_id = client.create_event(&admin, &event_params);
         let buy_params = BuyTicketParams {
-            event_id: event_id.clone(),
+            event_id,
             buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
         };
+        client.buy_ticket(&buyer, &buy_params);
+        assert_eq!(client.get_event_escrow(&event_id), 0);
         
-        let ticket_id = env.as_contract(&contract_id, || {
-            EventXContract::buy_ticket(&env, buyer.clone(), buy_params)
+        // Bekleyen ücret hazineye aktarıldıktan sonra iade karşılanamaz
+        env.ledger().with_mut(|li| {
+            li.timestamp = 1234567891 + client.get_hold_period();
         });
+        assert_eq!(client.withdraw_revenue(&event_id), 0);
+        assert_eq!(client.get_platform_balance(), 1000);
+        assert_eq!(
+            client.try_withdraw_revenue(&event_id),
+            Err(Ok(Error::NothingToWithdraw))
+        );
+        assert_eq!(
+            client.try_cancel_event(&admin, &event_id, &0),
+            Err(Ok(Error::RevenueWithdrawn))
+        );
+    }
+
+    #[test]
+    fn test_platform_fee_withdrawal_through_council() {
+        let env = Env::default();
+        let (contract_id, admin, buyer, event_id, _ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let token_client = token::Client::new(&env, &client.get_token());
+        let m1 = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let m2 = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let treasury = <soroban_sdk::Address as TestAddress>::generate(&env);
         
-        let ticket = env.as_contract(&contract_id, || {
-            EventXContract::get_ticket(&env, ticket_id)
-        }).unwrap();
+        client.set_platform_fee(&admin, &FeeConfig { bps: 0, flat: 100 });
+        client.buy_ticket(
+            &buyer,
+            &BuyTicketParams {
+                event_id,
+                buyer: buyer.clone(),
+                tier: None,
+                seat: TicketSeat::Unassigned,
+                quantity: 1,
+            },
+        );
+        env.ledger().with_mut(|li| {
+            li.timestamp = 1234567891 + client.get_hold_period();
+        });
+        client.withdraw_revenue(&event_id);
         
-        assert_eq!(ticket.owner, buyer);
-        assert_eq!(ticket.event_id, event_id);
-        assert!(!ticket.is_used);
-        assert!(!ticket.is_refunded);
+        // Konsey kurulduktan sonra admin tek başına ücret çekemez
+        client.set_council(&admin, &vec![&env, m1.clone(), m2.clone()], &2);
+        assert_eq!(
+            client.try_withdraw_platform_fees(&admin, &treasury),
+            Err(Ok(Error::CouncilRequired))
+        );
         
-        let ticket_count = env.as_contract(&contract_id, || {
-            EventXContract::get_event_ticket_count(&env, event_id)
-        });
-        assert_eq!(ticket_count, 1);
+        let withdraw = CouncilAction::WithdrawPlatformFees(treasury.clone());
+        let expires_at = env.ledger().timestamp() + 1000;
+        let proposal_id = client.propose_council_action(&m1, &withdraw, &expires_at);
+        assert_eq!(token_client.balance(&treasury), 0);
+        assert!(client.approve_council_action(&m2, &proposal_id));
+        assert_eq!(token_client.balance(&treasury), 100);
+        assert_eq!(client.get_platform_balance(), 0);
     }
 
     #[test]
-    fn test_get_all_events() {
+    fn test_ticket_tiers() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, EventXContract);
-        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let (contract_id, admin, buyer, _event_id, _ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let token_client = token::Client::new(&env, &client.get_token());
         
-        env.as_contract(&contract_id, || {
-            EventXContract::initialize(&env, admin.clone());
-        });
-        
-        let event_params1 = CreateEventParams {
-            title: symbol_short!("Event1"),
-            description: symbol_short!("Desc1"),
-            total_tickets: 50,
+        // GA ve VIP kategorileri event ile birlikte oluşturulur
+        let event_params = CreateEventParams {
+            title: symbol_short!("Concert"),
+            description: symbol_short!("Tiers"),
+            total_tickets: 100,
             ticket_price: 500,
             event_date: 1234567890,
+            tiers: vec![
+                &env,
+                TicketTierParams {
+                    name: symbol_short!("GA"),
+                    price: 1000,
+                    capacity: 50,
+                    has_perks: false,
+                },
+                TicketTierParams {
+                    name: symbol_short!("VIP"),
+                    price: 3000,
+                    capacity: 1,
+                    has_perks: true,
+                },
+            ],
         };
+        let event_id = client.create_event(&admin, &event_params);
+        assert_eq!(client.get_event(&event_id).tier_count, 2);
         
-        env.as_contract(&contract_id, || {
-            EventXContract::create_event(&env, admin.clone(), event_params1)
-        });
+        // Backstage kategorisi sonradan eklenir
+        let backstage_id = client.add_ticket_tier(
+            &admin,
+            &event_id,
+            &TicketTierParams {
+                name: symbol_short!("Backstg"),
+                price: 0,
+                capacity: 2,
+                has_perks: true,
+            },
+        );
+        assert_eq!(backstage_id, 3);
+        assert_eq!(
+            last_events(&env, &contract_id, 1),
+            vec![
+                &env,
+                (
+                    (symbol_short!("tier"), symbol_short!("added"), event_id).into_val(&env),
+                    TierAddedPayload {
+                        version: EVENTS_VERSION,
+                        tier: TicketTier {
+                            id: 3,
+                            name: symbol_short!("Backstg"),
+                            price: 0,
+                            capacity: 2,
+                            sold: 0,
+                            has_perks: true,
+                        },
+                    }
+                    .into_val(&env),
+                ),
+            ]
+        );
+        let tiers = client.get_ticket_tiers(&event_id);
+        assert_eq!(tiers.len(), 3);
+        assert_eq!(tiers.get(1).unwrap().name, symbol_short!("VIP"));
         
-        let event_params2 = CreateEventParams {
-            title: symbol_short!("Event2"),
-            description: symbol_short!("Desc2"),
-            total_tickets: 100,
-            ticket_price: 1000,
-            event_date: 1234567891,
+        // VIP bileti kategori fiyatından satılır ve kategori ticket'a yazılır
+        let vip_params = BuyTicketParams {
+            event_id,
+            buyer: buyer.clone(),
+            tier: Some(2),
+            seat: TicketSeat::Unassigned,
+            quantity: 1,
         };
+        let balance_before = token_client.balance(&buyer);
+        let vip_ticket = client.buy_ticket(&buyer, &vip_params).first().unwrap();
+        assert_eq!(token_client.balance(&buyer), balance_before - 3000);
+        let ticket = client.get_ticket(&vip_ticket);
+        assert_eq!(ticket.tier, Some(2));
+        assert_eq!(ticket.price_paid, 3000);
+        assert_eq!(client.get_ticket_tier(&event_id, &2).sold, 1);
         
-        env.as_contract(&contract_id, || {
-            EventXContract::create_event(&env, admin, event_params2)
-        });
+        // VIP tükenince diğer kategoriler ve standart bilet satılmaya devam eder
+        assert_eq!(
+            client.try_buy_ticket(&buyer, &vip_params),
+            Err(Ok(Error::TierSoldOut))
+        );
+        let ga_ticket = client.buy_ticket(
+            &buyer,
+            &BuyTicketParams {
+                event_id,
+                buyer: buyer.clone(),
+                tier: Some(1),
+                seat: TicketSeat::Unassigned,
+                quantity: 1,
+            },
+        ).first().unwrap();
+        assert_eq!(client.get_ticket(&ga_ticket).price_paid, 1000);
+        let standard_ticket = client.buy_ticket(
+            &buyer,
+            &BuyTicketParams {
+                event_id,
+                buyer: buyer.clone(),
+                tier: None,
+                seat: TicketSeat::Unassigned,
+                quantity: 1,
+            },
+        ).first().unwrap();
+        assert_eq!(client.get_ticket(&standard_ticket).price_paid, 500);
         
-        let all_events = env.as_contract(&contract_id, || {
-            EventXContract::get_all_events(&env)
-        });
+        let event = client.get_event(&event_id);
+        assert_eq!(event.tickets_sold, 3);
+        assert_eq!(event.escrow_balance, 4500);
         
-        assert_eq!(all_events.len(), 2);
-        let mut found_event1 = false;
-        let mut found_event2 = false;
-        for i in 0..all_events.len() {
-            let event = all_events.get(i).unwrap();
-            if event.title == symbol_short!("Event1") {
-                found_event1 = true;
-            }
-            if event.title == symbol_short!("Event2") {
-                found_event2 = true;
-            }
+        assert_eq!(
+            client.try_buy_ticket(
+                &buyer,
+                &BuyTicketParams {
+                    event_id,
+                    buyer: buyer.clone(),
+                    tier: Some(4),
+                    seat: TicketSeat::Unassigned,
+                    quantity: 1,
+                },
+            ),
+            Err(Ok(Error::TierNotFound))
+        );
+        
+        // İptalde her bilet için ödenen kategori fiyatı iade edilir
+        client.cancel_event(&admin, &event_id, &0);
+        assert_eq!(token_client.balance(&buyer), balance_before);
+    }
+
+    #[test]
+    fn test_add_ticket_tier_validation() {
+        let env = Env::default();
+        let (contract_id, admin, buyer, event_id, _ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let tier_params = TicketTierParams {
+            name: symbol_short!("VIP"),
+            price: 2000,
+            capacity: 10,
+            has_perks: true,
+        };
+        
+        // Sadece organizatör kategori ekleyebilir
+        assert_eq!(
+            client.try_add_ticket_tier(&buyer, &event_id, &tier_params),
+            Err(Ok(Error::Unauthorized))
+        );
+        assert_eq!(
+            client.try_add_ticket_tier(
+                &admin,
+                &event_id,
+                &TicketTierParams {
+                    capacity: 0,
+                    ..tier_params.clone()
+                },
+            ),
+            Err(Ok(Error::InvalidTier))
+        );
+        assert_eq!(
+            client.try_add_ticket_tier(
+                &admin,
+                &event_id,
+                &TicketTierParams {
+                    price: -1,
+                    ..tier_params.clone()
+                },
+            ),
+            Err(Ok(Error::InvalidPrice))
+        );
+        
+        // Kategori sayısı sınırlıdır
+        for _ in 0..MAX_TIERS {
+            client.add_ticket_tier(&admin, &event_id, &tier_params);
         }
-        assert!(found_event1);
-        assert!(found_event2);
+        assert_eq!(
+            client.try_add_ticket_tier(&admin, &event_id, &tier_params),
+            Err(Ok(Error::InvalidTier))
+        );
+        assert_eq!(
+            client.try_get_ticket_tier(&event_id, &(MAX_TIERS + 1)),
+            Err(Ok(Error::TierNotFound))
+        );
+        
+        // İptal edilmiş event'e kategori eklenemez
+        client.cancel_event(&admin, &event_id, &0);
+        assert_eq!(
+            client.try_add_ticket_tier(&admin, &event_id, &tier_params),
+            Err(Ok(Error::EventCancelled))
+        );
     }
 
     #[test]
-    fn test_transfer_ticket() {
+    fn test_reserved_seating() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, EventXContract);
-        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
-        let buyer = <soroban_sdk::Address as TestAddress>::generate(&env);
-        let new_owner = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let (contract_id, admin, buyer, event_id, _ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let token_client = token::Client::new(&env, &client.get_token());
+        let other = <soroban_sdk::Address as TestAddress>::generate(&env);
+        token::StellarAssetClient::new(&env, &client.get_token()).mint(&other, &10_000);
         
-        env.as_contract(&contract_id, || {
-            EventXContract::initialize(&env, admin.clone());
-        });
+        // 2 sıra x 3 koltukluk balkon bölümü
+        let section_id = client.add_seat_section(
+            &admin,
+            &event_id,
+            &SeatSectionParams {
+                name: symbol_short!("Balcony"),
+                rows: 2,
+                seats_per_row: 3,
+                price: 2500,
+            },
+        );
+        assert_eq!(section_id, 1);
+        assert_eq!(
+            last_events(&env, &contract_id, 1),
+            vec![
+                &env,
+                (
+                    (symbol_short!("section"), symbol_short!("added"), event_id).into_val(&env),
+                    SectionAddedPayload {
+                        version: EVENTS_VERSION,
+                        section: SeatSection {
+                            id: 1,
+                            name: symbol_short!("Balcony"),
+                            rows: 2,
+                            seats_per_row: 3,
+                            price: 2500,
+                            sold: 0,
+                        },
+                    }
+                    .into_val(&env),
+                ),
+            ]
+        );
+        assert_eq!(client.get_seat_sections(&event_id).len(), 1);
         
-        let event_params = CreateEventParams {
-            title: symbol_short!("TestEvent"),
-            description: symbol_short!("TestDesc"),
-            total_tickets: 100,
-            ticket_price: 1000,
-            event_date: 1234567890,
+        // Koltuk bölüm fiyatından satılır ve ticket'a yazılır
+        let seat = Seat {
+            section: section_id,
+            row: 2,
+            number: 1,
+        };
+        let seat_params = BuyTicketParams {
+            event_id,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Assigned(seat.clone()),
+            quantity: 1,
         };
+        let balance_before = token_client.balance(&buyer);
+        let ticket_id = client.buy_ticket(&buyer, &seat_params).first().unwrap();
+        assert_eq!(token_client.balance(&buyer), balance_before - 2500);
+        let ticket = client.get_ticket(&ticket_id);
+        assert_eq!(ticket.seat, TicketSeat::Assigned(seat.clone()));
+        assert_eq!(ticket.price_paid, 2500);
+        assert_eq!(client.get_seat_section(&event_id, &section_id).sold, 1);
+        assert_eq!(client.get_event(&event_id).tickets_sold, 2);
         
-        let event_id = env.as_contract(&contract_id, || {
-            EventXContract::create_event(&env, admin, event_params)
-        });
+        // Aynı koltuk ikinci kez satılamaz
+        let double_booking = BuyTicketParams {
+            buyer: other.clone(),
+            ..seat_params.clone()
+        };
+        assert_eq!(
+            client.try_buy_ticket(&other, &double_booking),
+            Err(Ok(Error::SeatTaken))
+        );
         
-        let buy_params = BuyTicketParams {
-            event_id: event_id.clone(),
+        // Koltuk transferle birlikte yeni sahibine geçer
+        client.transfer_ticket(
+            &buyer,
+            &TransferTicketParams {
+                ticket_id,
+                from: buyer.clone(),
+                to: other.clone(),
+            },
+        );
+        assert_eq!(
+            client.get_ticket(&ticket_id).seat,
+            TicketSeat::Assigned(seat.clone())
+        );
+        
+        // Geçersiz koltuk, bölüm ve kategoriyle birlikte koltuk seçimi reddedilir
+        let with_seat = |section: u32, row: u32, number: u32| BuyTicketParams {
+            event_id,
             buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Assigned(Seat {
+                section,
+                row,
+                number,
+            }),
+            quantity: 1,
         };
+        assert_eq!(
+            client.try_buy_ticket(&buyer, &with_seat(section_id, 3, 1)),
+            Err(Ok(Error::SeatNotFound))
+        );
+        assert_eq!(
+            client.try_buy_ticket(&buyer, &with_seat(section_id, 1, 0)),
+            Err(Ok(Error::SeatNotFound))
+        );
+        assert_eq!(
+            client.try_buy_ticket(&buyer, &with_seat(2, 1, 1)),
+            Err(Ok(Error::SectionNotFound))
+        );
+        assert_eq!(
+            client.try_buy_ticket(
+                &buyer,
+                &BuyTicketParams {
+                    tier: Some(1),
+                    ..with_seat(section_id, 1, 1)
+                },
+            ),
+            Err(Ok(Error::InvalidTier))
+        );
         
-        let ticket_id = env.as_contract(&contract_id, || {
-            EventXContract::buy_ticket(&env, buyer.clone(), buy_params)
-        });
-        
-        let transfer_params = TransferTicketParams {
-            ticket_id: ticket_id.clone(),
-            from: buyer,
-            to: new_owner.clone(),
+        // Boş koltuklar sayfa sayfa listelenir, satılan koltuk atlanır
+        let first_page = client.get_available_seats(&event_id, &section_id, &0, &4);
+        assert_eq!(first_page.seats.len(), 3);
+        assert_eq!(first_page.next_cursor, Some(4));
+        assert_eq!(
+            first_page.seats.get(2).unwrap(),
+            Seat {
+                section: section_id,
+                row: 1,
+                number: 3,
+            }
+        );
+        let second_page = client.get_available_seats(&event_id, &section_id, &4, &4);
+        assert_eq!(
+            second_page.seats,
+            vec![
+                &env,
+                Seat {
+                    section: section_id,
+                    row: 2,
+                    number: 2,
+                },
+                Seat {
+                    section: section_id,
+                    row: 2,
+                    number: 3,
+                },
+            ]
+        );
+        assert_eq!(second_page.next_cursor, None);
+        assert_eq!(
+            client.try_get_available_seats(&event_id, &2, &0, &0),
+            Err(Ok(Error::SectionNotFound))
+        );
+    }
+
+    #[test]
+    fn test_add_seat_section_validation() {
+        let env = Env::default();
+        let (contract_id, admin, buyer, event_id, _ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let section_params = SeatSectionParams {
+            name: symbol_short!("Stalls"),
+            rows: 10,
+            seats_per_row: 20,
+            price: 1500,
         };
         
-        let result = env.as_contract(&contract_id, || {
-            EventXContract::transfer_ticket(&env, new_owner.clone(), transfer_params)
-        });
-        assert!(result);
+        // Sadece organizatör bölüm ekleyebilir
+        assert_eq!(
+            client.try_add_seat_section(&buyer, &event_id, &section_params),
+            Err(Ok(Error::Unauthorized))
+        );
+        assert_eq!(
+            client.try_add_seat_section(
+                &admin,
+                &event_id,
+                &SeatSectionParams {
+                    rows: 0,
+                    ..section_params.clone()
+                },
+            ),
+            Err(Ok(Error::InvalidSection))
+        );
+        assert_eq!(
+            client.try_add_seat_section(
+                &admin,
+                &event_id,
+                &SeatSectionParams {
+                    seats_per_row: 0,
+                    ..section_params.clone()
+                },
+            ),
+            Err(Ok(Error::InvalidSection))
+        );
+        assert_eq!(
+            client.try_add_seat_section(
+                &admin,
+                &event_id,
+                &SeatSectionParams {
+                    price: -1,
+                    ..section_params.clone()
+                },
+            ),
+            Err(Ok(Error::InvalidPrice))
+        );
         
-        let ticket = env.as_contract(&contract_id, || {
-            EventXContract::get_ticket(&env, ticket_id)
-        }).unwrap();
+        // Bölüm sayısı sınırlıdır
+        for _ in 0..MAX_SECTIONS {
+            client.add_seat_section(&admin, &event_id, &section_params);
+        }
+        assert_eq!(
+            client.try_add_seat_section(&admin, &event_id, &section_params),
+            Err(Ok(Error::InvalidSection))
+        );
+        assert_eq!(
+            client.try_get_seat_section(&event_id, &(MAX_SECTIONS + 1)),
+            Err(Ok(Error::SectionNotFound))
+        );
         
-        assert_eq!(ticket.owner, new_owner);
+        // İptal edilmiş event'e bölüm eklenemez
+        client.cancel_event(&admin, &event_id, &0);
+        assert_eq!(
+            client.try_add_seat_section(&admin, &event_id, &section_params),
+            Err(Ok(Error::EventCancelled))
+        );
     }
 
     #[test]
-    fn test_use_ticket() {
+    fn test_buy_multiple_tickets() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, EventXContract);
-        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
-        let buyer = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let (contract_id, admin, buyer, event_id, first_ticket) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let token = client.get_token();
+        let token_client = token::Client::new(&env, &token);
+        let token_events = |env: &Env| {
+            env.events()
+                .all()
+                .iter()
+                .filter(|(address, _, _)| address == &token)
+                .count()
+        };
         
-        env.as_contract(&contract_id, || {
-            EventXContract::initialize(&env, admin.clone());
-        });
+        // 5 bilet tek çağrıda, tek token transferiyle alınır
+        let buy_params = BuyTicketParams {
+            event_id,
+            buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 5,
+        };
+        let balance_before = token_client.balance(&buyer);
+        let token_events_before = token_events(&env);
+        let ticket_ids = client.buy_ticket(&buyer, &buy_params);
+        assert_eq!(token_events(&env), token_events_before + 1);
+        assert_eq!(token_client.balance(&buyer), balance_before - 5000);
+        assert_eq!(
+            ticket_ids,
+            vec![
+                &env,
+                first_ticket + 1,
+                first_ticket + 2,
+                first_ticket + 3,
+                first_ticket + 4,
+                first_ticket + 5,
+            ]
+        );
         
-        let event_params = CreateEventParams {
-            title: symbol_short!("TestEvent"),
-            description: symbol_short!("TestDesc"),
-            total_tickets: 100,
-            ticket_price: 1000,
-            event_date: 1234567890,
+        // Her ticket ayrı seri numarası ve sold event'i alır
+        for (offset, ticket_id) in ticket_ids.iter().enumerate() {
+            let ticket = client.get_ticket(&ticket_id);
+            assert_eq!(ticket.owner, buyer);
+            assert_eq!(ticket.serial, 2 + offset as u32);
+            assert_eq!(ticket.price_paid, 1000);
+        }
+        assert_eq!(last_events(&env, &contract_id, 5).len(), 5);
+        assert_eq!(client.ticket_count(), first_ticket + 5);
+        assert_eq!(client.event_ticket_serial(&event_id), 6);
+        assert_eq!(client.get_user_ticket_count(&buyer), 6);
+        
+        let event = client.get_event(&event_id);
+        assert_eq!(event.tickets_sold, 6);
+        assert_eq!(event.escrow_balance, 6000);
+        
+        // Alım sınırı event başına ayarlanır
+        let over_limit = BuyTicketParams {
+            quantity: DEFAULT_MAX_PER_PURCHASE + 1,
+            ..buy_params.clone()
+        };
+        assert_eq!(
+            client.try_buy_ticket(&buyer, &over_limit),
+            Err(Ok(Error::InvalidQuantity))
+        );
+        assert_eq!(
+            client.try_buy_ticket(
+                &buyer,
+                &BuyTicketParams {
+                    quantity: 0,
+                    ..buy_params.clone()
+                },
+            ),
+            Err(Ok(Error::InvalidQuantity))
+        );
+        assert_eq!(
+            client.try_set_purchase_limit(&buyer, &event_id, &3),
+            Err(Ok(Error::Unauthorized))
+        );
+        assert_eq!(
+            client.try_set_purchase_limit(&admin, &event_id, &(MAX_PER_PURCHASE + 1)),
+            Err(Ok(Error::InvalidQuantity))
+        );
+        client.set_purchase_limit(&admin, &event_id, &3);
+        assert_eq!(client.get_event(&event_id).max_per_purchase, 3);
+        let within_limit = BuyTicketParams {
+            quantity: 3,
+            ..buy_params.clone()
         };
+        assert_eq!(
+            client.try_buy_ticket(
+                &buyer,
+                &BuyTicketParams {
+                    quantity: 4,
+                    ..buy_params.clone()
+                },
+            ),
+            Err(Ok(Error::InvalidQuantity))
+        );
+        token::StellarAssetClient::new(&env, &token).mint(&buyer, &3_000);
+        assert_eq!(client.buy_ticket(&buyer, &within_limit).len(), 3);
         
-        let event_id = env.as_contract(&contract_id, || {
-            EventXContract::create_event(&env, admin.clone(), event_params)
-        });
+        // Koltuklu alımda tek bilet alınabilir
+        client.add_seat_section(
+            &admin,
+            &event_id,
+            &SeatSectionParams {
+                name: symbol_short!("Stalls"),
+                rows: 1,
+                seats_per_row: 10,
+                price: 1000,
+            },
+        );
+        assert_eq!(
+            client.try_buy_ticket(
+                &buyer,
+                &BuyTicketParams {
+                    seat: TicketSeat::Assigned(Seat {
+                        section: 1,
+                        row: 1,
+                        number: 1,
+                    }),
+                    quantity: 2,
+                    ..buy_params.clone()
+                },
+            ),
+            Err(Ok(Error::InvalidQuantity))
+        );
+    }
+
+    #[test]
+    fn test_buy_multiple_tickets_is_atomic() {
+        let env = Env::default();
+        let (contract_id, admin, buyer, _event_id, first_ticket) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let token_client = token::Client::new(&env, &client.get_token());
         
+        let event_id = client.create_event(
+            &admin,
+            &CreateEventParams {
+                title: symbol_short!("Small"),
+                description: symbol_short!("Atomic"),
+                total_tickets: 5,
+                ticket_price: 100,
+                event_date: 1234567890,
+                tiers: vec![
+                    &env,
+                    TicketTierParams {
+                        name: symbol_short!("VIP"),
+                        price: 500,
+                        capacity: 2,
+                        has_perks: true,
+                    },
+                ],
+            },
+        );
         let buy_params = BuyTicketParams {
-            event_id: event_id.clone(),
+            event_id,
             buyer: buyer.clone(),
+            tier: None,
+            seat: TicketSeat::Unassigned,
+            quantity: 2,
         };
+        client.buy_ticket(&buyer, &buy_params);
+        let balance_before = token_client.balance(&buyer);
         
-        let ticket_id = env.as_contract(&contract_id, || {
-            EventXContract::buy_ticket(&env, buyer, buy_params)
-        });
+        // Kalan kapasite yetmezse hiçbir bilet oluşturulmaz ve ücret çekilmez
+        assert_eq!(
+            client.try_buy_ticket(
+                &buyer,
+                &BuyTicketParams {
+                    quantity: 4,
+                    ..buy_params.clone()
+                },
+            ),
+            Err(Ok(Error::SoldOut))
+        );
         
-        let result = env.as_contract(&contract_id, || {
-            EventXContract::use_ticket(&env, admin, ticket_id.clone())
-        });
-        assert!(result);
+        // Kategori kapasitesi de alınan adedin tamamı için kontrol edilir
+        assert_eq!(
+            client.try_buy_ticket(
+                &buyer,
+                &BuyTicketParams {
+                    tier: Some(1),
+                    quantity: 3,
+                    ..buy_params.clone()
+                },
+            ),
+            Err(Ok(Error::TierSoldOut))
+        );
         
-        let ticket = env.as_contract(&contract_id, || {
-            EventXContract::get_ticket(&env, ticket_id)
-        }).unwrap();
+        assert_eq!(token_client.balance(&buyer), balance_before);
+        assert_eq!(client.get_event(&event_id).tickets_sold, 2);
+        assert_eq!(client.get_ticket_tier(&event_id, &1).sold, 0);
+        assert_eq!(client.ticket_count(), first_ticket + 2);
         
-        assert!(ticket.is_used);
+        // Kalan kapasitenin tamamı alınabilir
+        assert_eq!(
+            client
+                .buy_ticket(
+                    &buyer,
+                    &BuyTicketParams {
+                        quantity: 3,
+                        ..buy_params.clone()
+                    },
+                )
+                .len(),
+            3
+        );
+        assert_eq!(
+            client.try_buy_ticket(
+                &buyer,
+                &BuyTicketParams {
+                    quantity: 1,
+                    ..buy_params
+                },
+            ),
+            Err(Ok(Error::SoldOut))
+        );
     }
 
     #[test]
-    fn test_cancel_event() {
+    fn test_owner_index_with_archived_event() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, EventXContract);
-        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
-        let buyer = <soroban_sdk::Address as TestAddress>::generate(&env);
-        
-        env.as_contract(&contract_id, || {
-            EventXContract::initialize(&env, admin.clone());
-        });
-        
-        let event_params = CreateEventParams {
-            title: symbol_short!("TestEvent"),
-            description: symbol_short!("TestDesc"),
-            total_tickets: 100,
-            ticket_price: 1000,
-            event_date: 1234567890,
+        let (contract_id, admin, buyer, old_event, old_ticket) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let receiver = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let all = TicketFilter {
+            event_id: None,
+            statuses: Vec::new(&env),
         };
         
-        let event_id = env.as_contract(&contract_id, || {
-            EventXContract::create_event(&env, admin.clone(), event_params)
-        });
+        // Aynı alıcının arşivlenmeyecek ileri tarihli bir event'te de bileti var
+        let new_event = client.create_event(
+            &admin,
+            &CreateEventParams {
+                title: symbol_short!("Later"),
+                description: symbol_short!("TestDesc"),
+                total_tickets: 100,
+                ticket_price: 1000,
+                event_date: 1234567890 + 365 * 24 * 60 * 60,
+                tiers: Vec::new(&env),
+            },
+        );
+        let new_ticket = client
+            .buy_ticket(
+                &buyer,
+                &BuyTicketParams {
+                    event_id: new_event,
+                    buyer: buyer.clone(),
+                    tier: None,
+                    seat: TicketSeat::Unassigned,
+                    quantity: 1,
+                },
+            )
+            .first()
+            .unwrap();
         
-        let buy_params = BuyTicketParams {
-            event_id: event_id.clone(),
-            buyer: buyer.clone(),
-        };
+        // İndeksin son kaydı eski event'in ticket'ı olur
+        client.buy_ticket(
+            &buyer,
+            &BuyTicketParams {
+                event_id: old_event,
+                buyer: buyer.clone(),
+                tier: None,
+                seat: TicketSeat::Unassigned,
+                quantity: 1,
+            },
+        );
         
-        env.as_contract(&contract_id, || {
-            EventXContract::buy_ticket(&env, buyer, buy_params)
+        // Eski event'in geliri çekilir ve arşiv süresi dolar
+        env.ledger().with_mut(|li| {
+            li.timestamp = 1234567890 + REVENUE_HOLD_PERIOD + 1;
         });
-        
-        let result = env.as_contract(&contract_id, || {
-            EventXContract::cancel_event(&env, admin, event_id.clone())
+        client.withdraw_revenue(&old_event);
+        env.ledger().with_mut(|li| {
+            li.timestamp = 1234567890 + ARCHIVE_AFTER + 1;
         });
-        assert!(result);
         
-        let event = env.as_contract(&contract_id, || {
-            EventXContract::get_event(&env, event_id)
-        }).unwrap();
+        // Arşivlenecek event'in ticket'ı "Biletlerim" sayfasında atlanır
+        assert_eq!(
+            page_ids(&client.get_user_tickets(&buyer, &0, &0, &all)),
+            std::vec![new_ticket]
+        );
+        assert_eq!(client.get_ticket(&old_ticket).owner, buyer);
         
-        assert!(event.is_cancelled);
-        assert!(!event.is_active);
+        // Yeni event canlı tutulurken eski event'in kayıtlarının TTL'i dolar
+        for _ in 0..3 {
+            env.ledger().with_mut(|li| {
+                li.sequence_number += 25 * DAY_IN_LEDGERS;
+            });
+            client.bump_event(&new_event, &0, &0);
+        }
+        
+        // Transfer arşivlenmiş ticket'ın indeks kaydına dokunmaz
+        client.transfer_ticket(
+            &buyer,
+            &TransferTicketParams {
+                ticket_id: new_ticket,
+                from: buyer.clone(),
+                to: receiver.clone(),
+            },
+        );
+        assert_eq!(client.get_user_ticket_count(&buyer), 2);
+        assert_eq!(client.get_user_ticket_count(&receiver), 1);
+        assert_eq!(
+            page_ids(&client.get_user_tickets(&receiver, &0, &0, &all)),
+            std::vec![new_ticket]
+        );
     }
 
     #[test]
-    fn test_is_ticket_valid() {
+    fn test_config_changes_through_council() {
         let env = Env::default();
-        let contract_id = env.register_contract(None, EventXContract);
-        let admin = <soroban_sdk::Address as TestAddress>::generate(&env);
-        let buyer = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let (contract_id, admin, _buyer, _event_id, _ticket_id) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let m1 = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let m2 = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let organizer = <soroban_sdk::Address as TestAddress>::generate(&env);
+        let fee = FeeConfig { bps: 100, flat: 5 };
+        let ttl = TtlConfig {
+            instance_threshold: 100,
+            instance_extend_to: 200,
+            persistent_threshold: 100,
+            persistent_extend_to: 200,
+            archive_after: 1000,
+        };
         
-        env.as_contract(&contract_id, || {
-            EventXContract::initialize(&env, admin.clone());
-        });
+        // Konsey kurulduktan sonra admin ayarları tek başına değiştiremez
+        client.set_council(&admin, &vec![&env, m1.clone(), m2.clone()], &2);
+        assert_eq!(
+            client.try_set_hold_period(&admin, &0),
+            Err(Ok(Error::CouncilRequired))
+        );
+        assert_eq!(
+            client.try_set_platform_fee(&admin, &fee),
+            Err(Ok(Error::CouncilRequired))
+        );
+        assert_eq!(
+            client.try_set_organizer_fee(&admin, &organizer, &Some(fee.clone())),
+            Err(Ok(Error::CouncilRequired))
+        );
+        assert_eq!(
+            client.try_set_ttl_config(&admin, &ttl),
+            Err(Ok(Error::CouncilRequired))
+        );
         
-        let event_params = CreateEventParams {
-            title: symbol_short!("TestEvent"),
-            description: symbol_short!("TestDesc"),
-            total_tickets: 100,
-            ticket_price: 1000,
-            event_date: 1234567890,
-        };
+        // Her değişiklik konsey onayıyla uygulanır
+        let actions = vec![
+            &env,
+            CouncilAction::SetHoldPeriod(3600),
+            CouncilAction::SetPlatformFee(fee.clone()),
+            CouncilAction::SetOrganizerFee(organizer.clone(), fee.clone()),
+            CouncilAction::SetTtlConfig(ttl.clone()),
+        ];
+        for action in actions.iter() {
+            let proposal_id = client.propose_council_action(&m1, &action, &1000);
+            assert!(client.approve_council_action(&m2, &proposal_id));
+        }
+        assert_eq!(client.get_hold_period(), 3600);
+        assert_eq!(client.get_platform_fee(), fee);
+        assert_eq!(client.get_organizer_fee(&organizer), Some(fee.clone()));
+        assert_eq!(client.get_ttl_config(), ttl);
         
-        let event_id = env.as_contract(&contract_id, || {
-            EventXContract::create_event(&env, admin, event_params)
-        });
+        let clear = CouncilAction::ClearOrganizerFee(organizer.clone());
+        let proposal_id = client.propose_council_action(&m1, &clear, &1000);
+        assert!(client.approve_council_action(&m2, &proposal_id));
+        assert_eq!(client.get_organizer_fee(&organizer), None);
         
-        let buy_params = BuyTicketParams {
-            event_id: event_id.clone(),
-            buyer: buyer.clone(),
-        };
+        // Geçersiz ücret onaylandığında uygulanmaz
+        let invalid = CouncilAction::SetPlatformFee(FeeConfig { bps: 10_001, flat: 0 });
+        let proposal_id = client.propose_council_action(&m1, &invalid, &1000);
+        assert_eq!(
+            client.try_approve_council_action(&m2, &proposal_id),
+            Err(Ok(Error::InvalidFee))
+        );
+        assert_eq!(client.get_platform_fee(), fee);
+    }
+
+    #[test]
+    fn test_buy_multiple_tickets_price_overflow() {
+        let env = Env::default();
+        let (contract_id, admin, buyer, _event_id, first_ticket) = setup_ticket(&env);
+        let client = EventXContractClient::new(&env, &contract_id);
+        let token_client = token::Client::new(&env, &client.get_token());
         
-        let ticket_id = env.as_contract(&contract_id, || {
-            EventXContract::buy_ticket(&env, buyer, buy_params)
-        });
+        let event_id = client.create_event(
+            &admin,
+            &CreateEventParams {
+                title: symbol_short!("Huge"),
+                description: symbol_short!("Overflow"),
+                total_tickets: 10,
+                ticket_price: i128::MAX / 2,
+                event_date: 1234567890,
+                tiers: Vec::new(&env),
+            },
+        );
+        let balance_before = token_client.balance(&buyer);
         
-        let is_valid = env.as_contract(&contract_id, || {
-            EventXContract::is_ticket_valid(&env, ticket_id)
-        });
-        assert!(is_valid);
+        // Toplam fiyat taşarsa satış reddedilir
+        assert_eq!(
+            client.try_buy_ticket(
+                &buyer,
+                &BuyTicketParams {
+                    event_id,
+                    buyer: buyer.clone(),
+                    tier: None,
+                    seat: TicketSeat::Unassigned,
+                    quantity: 3,
+                },
+            ),
+            Err(Ok(Error::InvalidPrice))
+        );
+        assert_eq!(token_client.balance(&buyer), balance_before);
+        assert_eq!(client.get_event(&event_id).tickets_sold, 0);
+        assert_eq!(client.ticket_count(), first_ticket);
     }
 } 
\ No newline at end of file